@@ -0,0 +1,42 @@
+//! Compares the binary search lookup with the cached linear scan lookup.
+//!
+//! Run with `cargo bench --bench lookup`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use max31865::temp_conversion::{CachedLookup, LOOKUP_VEC_PT100};
+
+const ROUNDS: usize = 200;
+
+/// Slowly changing readings sweeping 0 C° to 200 C° and back.
+fn readings() -> Vec<i32> {
+    let up = (10_000..17_586).step_by(3);
+    let down = (10_000..17_586).rev().step_by(3);
+    up.chain(down).collect()
+}
+
+fn main() {
+    let readings = readings();
+    let count = (readings.len() * ROUNDS) as u32;
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for ohm_100 in readings.iter() {
+            black_box(LOOKUP_VEC_PT100.lookup_temperature(black_box(*ohm_100)));
+        }
+    }
+    let binary = start.elapsed() / count;
+
+    let mut cached = CachedLookup::new(&LOOKUP_VEC_PT100);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for ohm_100 in readings.iter() {
+            black_box(cached.lookup_temperature(black_box(*ohm_100)));
+        }
+    }
+    let linear = start.elapsed() / count;
+
+    println!("binary search lookup: {:?} per lookup", binary);
+    println!("cached lookup:        {:?} per lookup", linear);
+}
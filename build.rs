@@ -0,0 +1,100 @@
+//! Generates the high resolution lookup tables of the `generated-table`
+//! feature, see the `temp_conversion` module.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const MIN: i32 = -200;
+const MAX: i32 = 860;
+const DEFAULT_STEP: i32 = 1;
+
+const CVD_A: f64 = 3.9083e-3;
+const CVD_B: f64 = -5.775e-7;
+const CVD_C: f64 = -4.18301e-12;
+
+/// The resistance of an RTD at the given temperature according to the
+/// Callendar–Van Dusen equation.
+fn cvd_resistance(celsius: f64, r0: f64) -> f64 {
+    let c = if celsius < 0.0 { CVD_C } else { 0.0 };
+    r0 * (1.0
+        + CVD_A * celsius
+        + CVD_B * celsius * celsius
+        + c * (celsius - 100.0) * celsius * celsius * celsius)
+}
+
+/// The resistance in ohms multiplied by 100, as stored in the tables.
+fn ohms_100(celsius: i32, r0: f64) -> u32 {
+    (cvd_resistance(celsius as f64, r0) * 100.0).round() as u32
+}
+
+fn table(name: &str, r0: f64, step: i32) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "/// Generated lookup table for a {} Ohm RTD ranging from {} C° up to {} C° in steps of {} C°.",
+        r0, MIN, MAX, step
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub const {}: LookupTable<'static, u32> = LookupTable {{",
+        name
+    )
+    .unwrap();
+    writeln!(out, "    min: {},", MIN).unwrap();
+    writeln!(out, "    step: {},", step).unwrap();
+    writeln!(out, "    data: &[").unwrap();
+
+    let mut celsius = MIN;
+    while celsius < MAX + step {
+        writeln!(out, "        {},", ohms_100(celsius, r0)).unwrap();
+        celsius += step;
+    }
+
+    writeln!(out, "    ],").unwrap();
+    writeln!(out, "}};").unwrap();
+    out
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=MAX31865_TABLE_STEP");
+
+    if env::var_os("CARGO_FEATURE_GENERATED_TABLE").is_none() {
+        return;
+    }
+
+    let step = match env::var("MAX31865_TABLE_STEP") {
+        Ok(step) => step
+            .parse::<i32>()
+            .ok()
+            .filter(|step| (1..=20).contains(step))
+            .expect("MAX31865_TABLE_STEP must be a whole number of degrees from 1 to 20"),
+        Err(_) => DEFAULT_STEP,
+    };
+
+    // Validate the equation against the IEC 60751 table for a PT100.
+    for (celsius, expected) in [
+        (-200, 1852),
+        (-100, 6026),
+        (0, 10000),
+        (100, 13851),
+        (850, 39048),
+    ] {
+        assert_eq!(
+            ohms_100(celsius, 100.0),
+            expected,
+            "PT100 at {} C°",
+            celsius
+        );
+    }
+
+    let mut out = String::new();
+    out += &table("LOOKUP_VEC_PT100_GENERATED", 100.0, step);
+    out += &table("LOOKUP_VEC_PT1000_GENERATED", 1000.0, step);
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("lookup_table.rs");
+    fs::write(path, out).unwrap();
+}
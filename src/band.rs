@@ -0,0 +1,109 @@
+//! Classification of temperatures into warning and critical bands
+
+/// The band a temperature reading falls into, see `classify`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Band {
+    Normal,
+    Warning,
+    Critical,
+}
+
+/// Thresholds used by `classify`, all in degrees Celsius multiplied by 100.
+///
+/// The thresholds are expected to be ordered as `critical_low <= warning_low
+/// <= warning_high <= critical_high`.
+#[derive(Clone, Copy, Debug)]
+pub struct Bands {
+    pub critical_low: i32,
+    pub warning_low: i32,
+    pub warning_high: i32,
+    pub critical_high: i32,
+}
+
+/// Classify a temperature reading into a band.
+///
+/// # Arguments
+///
+/// * `reading_c100` - The temperature in degrees Celsius multiplied by 100.
+/// * `bands` - The warning and critical thresholds.
+///
+/// # Remarks
+///
+/// The thresholds are inclusive, i.e. a reading exactly on a threshold is
+/// classified as the more severe band. E.g. with `warning_high` at `8000` a
+/// reading of `8000` is a `Band::Warning`, while `7999` is `Band::Normal`.
+pub fn classify(reading_c100: i32, bands: &Bands) -> Band {
+    if reading_c100 <= bands.critical_low || reading_c100 >= bands.critical_high {
+        Band::Critical
+    } else if reading_c100 <= bands.warning_low || reading_c100 >= bands.warning_high {
+        Band::Warning
+    } else {
+        Band::Normal
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{classify, Band, Bands};
+
+    #[test]
+    fn thresholds_are_inclusive() {
+        let bands = Bands {
+            critical_low: -4000,
+            warning_low: -2000,
+            warning_high: 8000,
+            critical_high: 9000,
+        };
+
+        let expected = [
+            (i32::MIN, Band::Critical),
+            (-4001, Band::Critical),
+            (-4000, Band::Critical),
+            (-3999, Band::Warning),
+            (-2001, Band::Warning),
+            (-2000, Band::Warning),
+            (-1999, Band::Normal),
+            (0, Band::Normal),
+            (7999, Band::Normal),
+            (8000, Band::Warning),
+            (8001, Band::Warning),
+            (8999, Band::Warning),
+            (9000, Band::Critical),
+            (9001, Band::Critical),
+            (i32::MAX, Band::Critical),
+        ];
+        for (reading, band) in expected {
+            assert_eq!(classify(reading, &bands), band, "{}", reading);
+        }
+    }
+
+    #[test]
+    fn negative_bands() {
+        // e.g. a freezer which must stay between -25 and -15 C°
+        let bands = Bands {
+            critical_low: -3000,
+            warning_low: -2500,
+            warning_high: -1500,
+            critical_high: -1000,
+        };
+
+        let expected = [
+            (-3001, Band::Critical),
+            (-3000, Band::Critical),
+            (-2999, Band::Warning),
+            (-2501, Band::Warning),
+            (-2500, Band::Warning),
+            (-2499, Band::Normal),
+            (-1501, Band::Normal),
+            (-1500, Band::Warning),
+            (-1499, Band::Warning),
+            (-1001, Band::Warning),
+            (-1000, Band::Critical),
+            (-999, Band::Critical),
+            (0, Band::Critical),
+        ];
+        for (reading, band) in expected {
+            assert_eq!(classify(reading, &bands), band, "{}", reading);
+        }
+    }
+}
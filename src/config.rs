@@ -0,0 +1,149 @@
+//! Builder for the configuration register
+
+use crate::{
+    FilterMode, SensorType, CONFIG_3WIRE, CONFIG_CONVERSION_MODE, CONFIG_FILTER_MODE, CONFIG_VBIAS,
+};
+
+/// A configuration of the device, applied with `Max31865::apply_config`.
+///
+/// This covers the persistent settings of the configuration register. The
+/// one-shot, fault detection cycle and fault status clear bits are commands
+/// rather than settings and are not part of it. The default configuration
+/// has V_BIAS off, normally off conversion mode, a two or four wire sensor
+/// and the 60Hz filter, matching the power on state of the device.
+///
+/// The configuration can be converted from and to the value of the
+/// configuration register without a device, e.g. to parse a register dump or
+/// to compare configurations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    vbias: bool,
+    conversion_mode: bool,
+    sensor_type: SensorType,
+    filter_mode: FilterMode,
+}
+
+impl Config {
+    /// Create the default configuration.
+    pub fn new() -> Self {
+        Config {
+            vbias: false,
+            conversion_mode: false,
+            sensor_type: SensorType::TwoOrFourWire,
+            filter_mode: FilterMode::Filter60Hz,
+        }
+    }
+
+    /// Enable or disable V_BIAS, which is required to perform conversions.
+    pub fn vbias(mut self, vbias: bool) -> Self {
+        self.vbias = vbias;
+        self
+    }
+
+    /// `true` to convert automatically, `false` for normally off mode.
+    pub fn conversion_mode(mut self, conversion_mode: bool) -> Self {
+        self.conversion_mode = conversion_mode;
+        self
+    }
+
+    /// Set the wiring of the sensor.
+    pub fn sensor_type(mut self, sensor_type: SensorType) -> Self {
+        self.sensor_type = sensor_type;
+        self
+    }
+
+    /// Set the mains frequency filter.
+    pub fn filter_mode(mut self, filter_mode: FilterMode) -> Self {
+        self.filter_mode = filter_mode;
+        self
+    }
+
+    /// Filter 50Hz mains noise, e.g. in Europe.
+    pub fn filter_50hz(self) -> Self {
+        self.filter_mode(FilterMode::Filter50Hz)
+    }
+
+    /// Filter 60Hz mains noise, e.g. in North America.
+    pub fn filter_60hz(self) -> Self {
+        self.filter_mode(FilterMode::Filter60Hz)
+    }
+
+    /// Decode the value of the configuration register.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The value of the configuration register.
+    ///
+    /// # Remarks
+    ///
+    /// The command bits (one-shot, fault detection cycle and fault status
+    /// clear) are ignored.
+    pub fn from_register(config: u8) -> Self {
+        Config {
+            vbias: config & CONFIG_VBIAS != 0,
+            conversion_mode: config & CONFIG_CONVERSION_MODE != 0,
+            sensor_type: if config & CONFIG_3WIRE != 0 {
+                SensorType::ThreeWire
+            } else {
+                SensorType::TwoOrFourWire
+            },
+            filter_mode: if config & CONFIG_FILTER_MODE != 0 {
+                FilterMode::Filter50Hz
+            } else {
+                FilterMode::Filter60Hz
+            },
+        }
+    }
+
+    /// Encode the configuration as the value of the configuration register.
+    ///
+    /// # Remarks
+    ///
+    /// The command bits are `0`, so writing the value doesn't trigger a
+    /// conversion or fault detection cycle.
+    pub fn to_register(&self) -> u8 {
+        ((self.vbias as u8) << 7)
+            | ((self.conversion_mode as u8) << 6)
+            | ((self.sensor_type as u8) << 4)
+            | (self.filter_mode as u8)
+    }
+
+    pub(crate) fn parts(&self) -> (bool, bool, SensorType, FilterMode) {
+        (
+            self.vbias,
+            self.conversion_mode,
+            self.sensor_type,
+            self.filter_mode,
+        )
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Config;
+    use crate::SensorType;
+
+    #[test]
+    fn register_round_trip() {
+        // The settings bits, without the command bits.
+        const SETTINGS: u8 = 0b1101_0001;
+
+        for register in 0..=u8::MAX {
+            let config = Config::from_register(register);
+            assert_eq!(config.to_register(), register & SETTINGS);
+            assert_eq!(Config::from_register(config.to_register()), config);
+        }
+
+        let config = Config::new()
+            .vbias(true)
+            .sensor_type(SensorType::ThreeWire)
+            .filter_50hz();
+        assert_eq!(config.to_register(), 0x91);
+    }
+}
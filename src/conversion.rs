@@ -0,0 +1,320 @@
+//! Conversion between the raw ADC code and resistance
+
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::MAX_CALIBRATION;
+
+/// A resistance in Ohms multiplied by 100, the scale of the resistances used
+/// by the driver.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ohms(pub u32);
+
+impl Ohms {
+    /// The resistance in Ohms multiplied by 100.
+    pub fn hundredths(&self) -> u32 {
+        self.0
+    }
+
+    /// The whole Ohms, e.g. `138` for 138.51 Ohms.
+    pub fn whole(&self) -> u32 {
+        self.0 / 100
+    }
+
+    /// The hundredths of an Ohm, e.g. `51` for 138.51 Ohms.
+    pub fn fraction(&self) -> u8 {
+        (self.0 % 100) as u8
+    }
+
+    /// Convert a resistance in milliohms, rounded to the nearest hundredth of
+    /// an Ohm.
+    pub fn from_milliohms(milliohms: u32) -> Self {
+        Ohms(milliohms / 10 + (milliohms % 10 >= 5) as u32)
+    }
+
+    /// The resistance in milliohms (Ohms multiplied by 1000).
+    pub fn to_milliohms(&self) -> u32 {
+        self.0.saturating_mul(10)
+    }
+
+    /// Convert a resistance in Ohms, rounded to the nearest hundredth of an
+    /// Ohm. Negative values are treated as `0`.
+    pub fn from_f32(ohms: f32) -> Self {
+        Ohms((ohms * 100.0 + 0.5) as u32)
+    }
+
+    /// The resistance in Ohms.
+    pub fn to_f32(&self) -> f32 {
+        self.0 as f32 / 100.0
+    }
+}
+
+impl From<Ohms> for u32 {
+    fn from(ohms: Ohms) -> u32 {
+        ohms.0
+    }
+}
+
+impl Add for Ohms {
+    type Output = Ohms;
+
+    fn add(self, other: Ohms) -> Ohms {
+        Ohms(self.0 + other.0)
+    }
+}
+
+impl Sub for Ohms {
+    type Output = Ohms;
+
+    /// Subtracts the resistances, saturating at `0`.
+    fn sub(self, other: Ohms) -> Ohms {
+        Ohms(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul<u32> for Ohms {
+    type Output = Ohms;
+
+    fn mul(self, factor: u32) -> Ohms {
+        Ohms(self.0 * factor)
+    }
+}
+
+impl Div<u32> for Ohms {
+    type Output = Ohms;
+
+    fn div(self, divisor: u32) -> Ohms {
+        Ohms(self.0 / divisor)
+    }
+}
+
+impl core::fmt::Display for Ohms {
+    /// Formats the resistance with two decimals and the unit, e.g.
+    /// `138.51Ω`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{:02}Ω", self.whole(), self.fraction())
+    }
+}
+
+/// Convert a 15 bit ADC code into a resistance.
+///
+/// # Arguments
+///
+/// * `code` - The 15 bit ADC code, i.e. the raw RTD value without the fault
+///            bit (`raw >> 1`).
+/// * `r_ref_ohms100` - The reference resistance in Ohms multiplied by 100.
+///
+/// # Remarks
+///
+/// The output is the resistance in Ohms multiplied by 100. The code is the
+/// ratio of the RTD resistance to the reference resistance scaled to 2^15.
+pub fn code_to_ohms100(code: u16, r_ref_ohms100: u32) -> u32 {
+    ((code as u64 * r_ref_ohms100 as u64) >> 15) as u32
+}
+
+/// Convert a 15 bit ADC code into a resistance in milliohms.
+///
+/// # Arguments
+///
+/// * `code` - The 15 bit ADC code, i.e. the raw RTD value without the fault
+///            bit (`raw >> 1`).
+/// * `r_ref_ohms100` - The reference resistance in Ohms multiplied by 100.
+///
+/// # Remarks
+///
+/// The output is the resistance in milliohms (Ohms multiplied by 1000), one
+/// more decimal than `code_to_ohms100`.
+pub fn code_to_milliohms(code: u16, r_ref_ohms100: u32) -> u32 {
+    ((code as u64 * r_ref_ohms100 as u64 * 10) >> 15) as u32
+}
+
+/// Convert a 15 bit ADC code into a resistance with the reference resistor
+/// and the RTD swapped, see `RefWiring::Swapped`.
+///
+/// # Arguments
+///
+/// * `code` - The 15 bit ADC code, i.e. the raw RTD value without the fault
+///            bit (`raw >> 1`).
+/// * `r_ref_ohms100` - The reference resistance in Ohms multiplied by 100.
+/// * `max_ohms100` - The largest resistance returned in Ohms multiplied by
+///                   100.
+///
+/// # Remarks
+///
+/// The output is the resistance in Ohms multiplied by 100. The code is the
+/// ratio of the reference resistance to the RTD resistance scaled to 2^15,
+/// so the resistance grows without bound as the code approaches `0`. It is
+/// therefore clamped to `max_ohms100`, e.g. the last entry of the lookup
+/// table, which is also returned for a code of `0`.
+pub fn swapped_code_to_ohms100(code: u16, r_ref_ohms100: u32, max_ohms100: u32) -> u32 {
+    if code == 0 {
+        return max_ohms100;
+    }
+
+    (((r_ref_ohms100 as u64) << 15) / code as u64).min(max_ohms100 as u64) as u32
+}
+
+/// Convert a 15 bit ADC code into a resistance in milliohms with the
+/// reference resistor and the RTD swapped, see `swapped_code_to_ohms100`.
+///
+/// # Arguments
+///
+/// * `code` - The 15 bit ADC code, i.e. the raw RTD value without the fault
+///            bit (`raw >> 1`).
+/// * `r_ref_ohms100` - The reference resistance in Ohms multiplied by 100.
+/// * `max_milliohms` - The largest resistance returned in milliohms.
+pub fn swapped_code_to_milliohms(code: u16, r_ref_ohms100: u32, max_milliohms: u32) -> u32 {
+    if code == 0 {
+        return max_milliohms;
+    }
+
+    (((r_ref_ohms100 as u64 * 10) << 15) / code as u64).min(max_milliohms as u64) as u32
+}
+
+/// Convert a resistance into the 15 bit ADC code it would be measured as with
+/// the reference resistor and the RTD swapped, the inverse of
+/// `swapped_code_to_ohms100`.
+///
+/// # Arguments
+///
+/// * `ohms100` - The resistance in Ohms multiplied by 100.
+/// * `r_ref_ohms100` - The reference resistance in Ohms multiplied by 100.
+///
+/// # Remarks
+///
+/// Resistances at or below the reference resistance saturate at the maximum
+/// code `0x7FFF`.
+pub fn ohms100_to_swapped_code(ohms100: u32, r_ref_ohms100: u32) -> u16 {
+    ohms100_to_code(r_ref_ohms100, ohms100)
+}
+
+/// Apply a two-point correction to a measured resistance, see
+/// `Max31865::set_two_point_calibration`.
+///
+/// # Arguments
+///
+/// * `ohms` - The measured resistance in Ohms multiplied by `100 * factor`.
+/// * `points` - The measured and actual resistances of the low and high
+///              calibration points in Ohms multiplied by 100.
+/// * `factor` - The scale of `ohms` relative to Ohms multiplied by 100, e.g.
+///              `10` for milliohms.
+///
+/// # Remarks
+///
+/// The output is the corrected resistance at the scale of `ohms`, limited to
+/// `0..=MAX_CALIBRATION * factor` so that it converts to a temperature
+/// without overflow. The resistance is returned unchanged if both measured
+/// resistances are equal.
+pub(crate) fn two_point_correction(ohms: i64, points: [(u32, u32); 2], factor: i64) -> i64 {
+    let [(m_lo, a_lo), (m_hi, a_hi)] = points;
+    if m_lo == m_hi {
+        return ohms;
+    }
+
+    let factor = factor as i128;
+    let corrected = a_lo as i128 * factor
+        + (ohms as i128 - m_lo as i128 * factor) * (a_hi as i128 - a_lo as i128)
+            / (m_hi as i128 - m_lo as i128);
+
+    corrected.clamp(0, MAX_CALIBRATION as i128 * factor) as i64
+}
+
+/// Convert a resistance into the 15 bit ADC code it would be measured as.
+///
+/// # Arguments
+///
+/// * `ohms100` - The resistance in Ohms multiplied by 100.
+/// * `r_ref_ohms100` - The reference resistance in Ohms multiplied by 100.
+///
+/// # Remarks
+///
+/// Resistances at or above the reference resistance saturate at the maximum
+/// code `0x7FFF`, as does any resistance with a reference resistance of `0`.
+pub fn ohms100_to_code(ohms100: u32, r_ref_ohms100: u32) -> u16 {
+    if r_ref_ohms100 == 0 {
+        return 0x7FFF;
+    }
+
+    let code = ((ohms100 as u64) << 15) / r_ref_ohms100 as u64;
+
+    if code > 0x7FFF {
+        0x7FFF
+    } else {
+        code as u16
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        code_to_milliohms, code_to_ohms100, ohms100_to_code, ohms100_to_swapped_code,
+        swapped_code_to_milliohms, swapped_code_to_ohms100, two_point_correction, Ohms,
+    };
+    use crate::MAX_CALIBRATION;
+
+    #[test]
+    fn test_ohms() {
+        let ohms = Ohms(13_851);
+        assert_eq!(ohms.whole(), 138);
+        assert_eq!(ohms.fraction(), 51);
+        assert_eq!(format!("{}", ohms), "138.51Ω");
+        assert_eq!(format!("{}", Ohms(5)), "0.05Ω");
+        assert_eq!(Ohms::from_milliohms(138_506), ohms);
+        assert_eq!(ohms.to_milliohms(), 138_510);
+        assert_eq!(Ohms::from_f32(138.51), ohms);
+        assert_eq!(ohms - Ohms(3_851), Ohms(10_000));
+        assert_eq!(Ohms(100) - ohms, Ohms(0));
+        assert_eq!((Ohms(10_000) + Ohms(50)) * 2 / 4, Ohms(5_025));
+    }
+
+    #[test]
+    fn test_code_conversion() {
+        assert_eq!(code_to_ohms100(8192, 40_000), 10_000);
+        assert_eq!(ohms100_to_code(10_000, 40_000), 8192);
+        assert_eq!(code_to_ohms100(0x7FFF, 430_000), 429_986);
+        assert_eq!(ohms100_to_code(50_000, 40_000), 0x7FFF);
+        assert_eq!(ohms100_to_code(10_000, 0), 0x7FFF);
+        assert_eq!(ohms100_to_code(0, 0), 0x7FFF);
+        assert_eq!(code_to_milliohms(8192, 40_000), 100_000);
+        assert_eq!(code_to_milliohms(0x7FFF, 430_000), 4_299_868);
+
+        for code in (0..0x7FFF).step_by(97) {
+            let ohms = code_to_ohms100(code, 43_000);
+            assert!(ohms100_to_code(ohms, 43_000) <= code);
+        }
+    }
+
+    #[test]
+    fn test_swapped_code_conversion() {
+        assert_eq!(swapped_code_to_ohms100(0x5C69, 10_000, 390_000), 13_851);
+        assert_eq!(ohms100_to_swapped_code(13_851, 10_000), 0x5C69);
+        assert_eq!(
+            swapped_code_to_milliohms(0x5C69, 10_000, 3_900_000),
+            138_512
+        );
+        assert_eq!(swapped_code_to_ohms100(1, 10_000, 390_000), 390_000);
+        assert_eq!(swapped_code_to_ohms100(0, 10_000, 390_000), 390_000);
+        assert_eq!(swapped_code_to_milliohms(0, 10_000, 3_900_000), 3_900_000);
+        assert_eq!(ohms100_to_swapped_code(5_000, 10_000), 0x7FFF);
+        assert_eq!(ohms100_to_swapped_code(0, 10_000), 0x7FFF);
+    }
+
+    #[test]
+    fn test_two_point_correction() {
+        let points = [(9_900, 10_000), (19_800, 20_100)];
+        assert_eq!(two_point_correction(9_900, points, 1), 10_000);
+        assert_eq!(two_point_correction(198_000, points, 10), 201_000);
+        assert_eq!(two_point_correction(0, [(0, 0), (0, 1)], 1), 0);
+
+        let extreme = [(0, 0), (1, u32::MAX)];
+        let limit = MAX_CALIBRATION as i64 * 10;
+        assert_eq!(
+            two_point_correction(u32::MAX as i64 * 10, extreme, 10),
+            limit
+        );
+        assert_eq!(
+            two_point_correction(-1, [(0, u32::MAX), (1, 0)], 1),
+            MAX_CALIBRATION as i64
+        );
+        assert_eq!(two_point_correction(2, [(0, u32::MAX), (1, 0)], 1), 0);
+    }
+}
@@ -0,0 +1,92 @@
+//! Over-sampling of consecutive conversions
+
+use crate::hal::blocking::spi;
+use crate::hal::digital::v2::{InputPin, OutputPin};
+use crate::{Error, Max31865, RtdCurve};
+
+/// Wraps the driver to average `R` consecutive conversions into one output.
+///
+/// Averaging `R` conversions reduces the noise and quantization steps by
+/// about `sqrt(R)`, i.e. roughly `log2(R) / 2` additional bits of resolution,
+/// at `1 / R` of the conversion rate. E.g. `R = 16` gives two extra bits and
+/// one output every 320 ms with the 50Hz filter. Only the resolution improves,
+/// not the absolute accuracy.
+///
+/// The device must be in automatic conversion mode, so that the samples are
+/// consecutive conversions. `poll` is expected to be called at least once per
+/// conversion, e.g. from the main loop, otherwise conversions are skipped.
+pub struct DecimatingReader<SPI, NCS, RDY, C, const R: usize> {
+    max31865: Max31865<SPI, NCS, RDY, C>,
+    sum: i64,
+    count: usize,
+}
+
+impl<E, SPI, NCS, RDY, C, const R: usize> DecimatingReader<SPI, NCS, RDY, C, R>
+where
+    SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    RDY: InputPin,
+    C: RtdCurve,
+{
+    /// Create a new decimating reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `max31865` - The driver configured for automatic conversion mode.
+    ///
+    /// # Remarks
+    ///
+    /// A decimation ratio `R` of `0` is treated as `1`.
+    pub fn new(max31865: Max31865<SPI, NCS, RDY, C>) -> Self {
+        DecimatingReader {
+            max31865,
+            sum: 0,
+            count: 0,
+        }
+    }
+
+    /// Accumulate the next conversion if one is available.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the average in millidegrees Celsius (degrees
+    /// multiplied by 1000), as the average is finer than the hundredths
+    /// returned by `read_default_conversion`. Returns `None` until `R`
+    /// conversions have been accumulated, after which the accumulator is
+    /// cleared. The samples are read with `read_temperature_millidegrees`.
+    pub fn poll(&mut self) -> Result<Option<i32>, Error<E>> {
+        if !self.max31865.is_ready()? {
+            return Ok(None);
+        }
+
+        self.sum += self.max31865.read_temperature_millidegrees()? as i64;
+        self.count += 1;
+
+        if self.count < R.max(1) {
+            return Ok(None);
+        }
+
+        let count = self.count as i64;
+        let average = (self.sum + count / 2).div_euclid(count);
+        self.reset();
+
+        Ok(Some(average as i32))
+    }
+
+    /// Discard the conversions accumulated so far, e.g. after changing the
+    /// configuration.
+    pub fn reset(&mut self) {
+        self.sum = 0;
+        self.count = 0;
+    }
+
+    /// Access the wrapped driver, e.g. to configure it.
+    pub fn inner_mut(&mut self) -> &mut Max31865<SPI, NCS, RDY, C> {
+        &mut self.max31865
+    }
+
+    /// Release the driver.
+    pub fn release(self) -> Max31865<SPI, NCS, RDY, C> {
+        self.max31865
+    }
+}
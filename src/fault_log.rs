@@ -0,0 +1,101 @@
+//! History of fault events
+
+use crate::FaultStatus;
+
+/// A fault recorded by a `FaultLog`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FaultEvent {
+    /// The tick at which the fault was recorded, in caller defined units.
+    pub tick: u32,
+    pub status: FaultStatus,
+}
+
+/// A ring buffer of the last `N` fault events.
+///
+/// Feed it the result of every `Max31865::read_fault_status` together with
+/// the current tick. A fault is only recorded when the status changes, so a
+/// fault that persists over several polls is recorded once, while a fault
+/// that clears and recurs is recorded again. Once full, the oldest event is
+/// overwritten.
+pub struct FaultLog<const N: usize> {
+    events: [FaultEvent; N],
+    next: usize,
+    len: usize,
+    last: FaultStatus,
+}
+
+impl<const N: usize> FaultLog<N> {
+    /// Create an empty fault log.
+    pub fn new() -> Self {
+        FaultLog {
+            events: [FaultEvent::default(); N],
+            next: 0,
+            len: 0,
+            last: FaultStatus::default(),
+        }
+    }
+
+    /// Record a fault status read at the given tick.
+    pub fn record(&mut self, status: FaultStatus, tick: u32) {
+        let changed = status != self.last;
+        self.last = status;
+
+        if N == 0 || !changed || !status.is_fault() {
+            return;
+        }
+
+        self.events[self.next] = FaultEvent { tick, status };
+        self.next = (self.next + 1) % N;
+        if self.len < N {
+            self.len += 1;
+        }
+    }
+
+    /// The number of recorded events.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no events have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all recorded events.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Iterate over the recorded events from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &FaultEvent> {
+        let start = if self.len < N { 0 } else { self.next };
+        (0..self.len).map(move |i| &self.events[(start + i) % N])
+    }
+}
+
+impl<const N: usize> Default for FaultLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FaultLog;
+    use crate::FaultStatus;
+
+    #[test]
+    fn test_fault_log() {
+        let mut log = FaultLog::<2>::new();
+        log.record(FaultStatus(0x00), 1);
+        log.record(FaultStatus(0x80), 2);
+        log.record(FaultStatus(0x80), 3);
+        log.record(FaultStatus(0x00), 4);
+        log.record(FaultStatus(0x80), 5);
+        log.record(FaultStatus(0x04), 6);
+
+        let ticks: Vec<u32> = log.iter().map(|e| e.tick).collect();
+        assert_eq!(ticks, [5, 6]);
+        assert_eq!(log.iter().last().unwrap().status, FaultStatus(0x04));
+    }
+}
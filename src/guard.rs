@@ -0,0 +1,232 @@
+//! Over temperature guard wrapping the driver
+
+use crate::hal::blocking::spi;
+use crate::hal::digital::v2::{InputPin, OutputPin};
+use crate::{Error, Max31865, RtdCurve};
+
+/// The level which the guard pin is driven to when the guard trips.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GuardPolarity {
+    ActiveHigh,
+    ActiveLow,
+}
+
+/// Wraps the driver together with an output pin which is asserted whenever
+/// the temperature exceeds a limit, e.g. to disable a heater relay.
+pub struct GuardedReader<SPI, NCS, RDY, C, G> {
+    max31865: Max31865<SPI, NCS, RDY, C>,
+    guard: G,
+    limit: i32,
+    polarity: GuardPolarity,
+}
+
+impl<E, SPI, NCS, RDY, C, G> GuardedReader<SPI, NCS, RDY, C, G>
+where
+    SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    RDY: InputPin,
+    C: RtdCurve,
+    G: OutputPin,
+{
+    /// Create a new guarded reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `max31865` - The configured driver to read the temperature from.
+    /// * `guard` - The output pin asserted when the limit is exceeded.
+    /// * `limit` - The temperature limit in degrees Celsius multiplied by 100.
+    /// * `polarity` - Whether asserting the guard pin drives it high or low.
+    pub fn new(
+        max31865: Max31865<SPI, NCS, RDY, C>,
+        guard: G,
+        limit: i32,
+        polarity: GuardPolarity,
+    ) -> Self {
+        GuardedReader {
+            max31865,
+            guard,
+            limit,
+            polarity,
+        }
+    }
+
+    /// Change the temperature limit (degrees Celsius multiplied by 100).
+    pub fn set_limit(&mut self, limit: i32) {
+        self.limit = limit;
+    }
+
+    /// Read the temperature and update the guard pin.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// The guard pin is driven on every read: it is asserted if the
+    /// temperature exceeds the limit and released otherwise. If the
+    /// temperature can't be read the guard pin is asserted before returning
+    /// the error, so that a failing sensor fails safe.
+    pub fn read_guarded(&mut self) -> Result<i32, Error<E>> {
+        match self.max31865.read_default_conversion() {
+            Ok(temp) => {
+                self.drive(temp > self.limit)?;
+                Ok(temp)
+            }
+            Err(e) => {
+                self.drive(true)?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Access the wrapped driver, e.g. to configure it.
+    pub fn inner_mut(&mut self) -> &mut Max31865<SPI, NCS, RDY, C> {
+        &mut self.max31865
+    }
+
+    /// Release the driver and the guard pin.
+    pub fn release(self) -> (Max31865<SPI, NCS, RDY, C>, G) {
+        (self.max31865, self.guard)
+    }
+
+    fn drive(&mut self, tripped: bool) -> Result<(), Error<E>> {
+        let high = tripped == (self.polarity == GuardPolarity::ActiveHigh);
+        if high {
+            self.guard.set_high().map_err(|_| Error::PinError)
+        } else {
+            self.guard.set_low().map_err(|_| Error::PinError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GuardPolarity, GuardedReader};
+    use crate::{Error, Max31865, CONFIG_VBIAS};
+    use embedded_hal_mock::pin::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+    type Reader = GuardedReader<SpiMock, PinMock, PinMock, crate::Pt100, PinMock>;
+
+    /// Create a guarded reader which reads 0 C° `reads` times.
+    fn reader(
+        reads: usize,
+        guard: &[PinTransaction],
+        limit: i32,
+        polarity: GuardPolarity,
+    ) -> Reader {
+        let mut spi = Vec::new();
+        let mut ncs = vec![PinTransaction::set(State::High)];
+        for _ in 0..reads {
+            spi.push(SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]));
+            spi.push(SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]));
+            for _ in 0..2 {
+                ncs.push(PinTransaction::set(State::Low));
+                ncs.push(PinTransaction::set(State::High));
+            }
+        }
+
+        let mut max =
+            Max31865::new(SpiMock::new(&spi), PinMock::new(&ncs), PinMock::new(&[])).unwrap();
+        max.config = CONFIG_VBIAS;
+        GuardedReader::new(max, PinMock::new(guard), limit, polarity)
+    }
+
+    fn done(reader: Reader) {
+        let (max, mut guard) = reader.release();
+        let Max31865 {
+            mut spi,
+            mut ncs,
+            mut rdy,
+            ..
+        } = max;
+        spi.done();
+        ncs.done();
+        rdy.done();
+        guard.done();
+    }
+
+    #[test]
+    fn trips_above_limit() {
+        let guard = [
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+        ];
+        let mut reader = reader(2, &guard, -1, GuardPolarity::ActiveHigh);
+
+        assert_eq!(reader.read_guarded().unwrap(), 0);
+        reader.set_limit(0);
+        assert_eq!(reader.read_guarded().unwrap(), 0);
+        done(reader);
+    }
+
+    #[test]
+    fn releases_at_or_below_limit() {
+        let guard = [
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::Low),
+        ];
+        let mut reader = reader(2, &guard, 0, GuardPolarity::ActiveHigh);
+
+        assert_eq!(reader.read_guarded().unwrap(), 0);
+        reader.set_limit(100);
+        assert_eq!(reader.read_guarded().unwrap(), 0);
+        done(reader);
+    }
+
+    #[test]
+    fn active_low() {
+        let guard = [
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+        ];
+        let mut reader = reader(2, &guard, -1, GuardPolarity::ActiveLow);
+
+        assert_eq!(reader.read_guarded().unwrap(), 0);
+        reader.set_limit(0);
+        assert_eq!(reader.read_guarded().unwrap(), 0);
+        done(reader);
+    }
+
+    /// An SPI bus on which every transfer fails.
+    struct FailingSpi;
+
+    impl embedded_hal::blocking::spi::Transfer<u8> for FailingSpi {
+        type Error = ();
+
+        fn transfer<'w>(&mut self, _words: &'w mut [u8]) -> Result<&'w [u8], ()> {
+            Err(())
+        }
+    }
+
+    impl embedded_hal::blocking::spi::Write<u8> for FailingSpi {
+        type Error = ();
+
+        fn write(&mut self, _words: &[u8]) -> Result<(), ()> {
+            Err(())
+        }
+    }
+
+    #[test]
+    fn spi_error_asserts_guard() {
+        for (polarity, asserted) in [
+            (GuardPolarity::ActiveHigh, State::High),
+            (GuardPolarity::ActiveLow, State::Low),
+        ] {
+            let ncs = PinMock::new(&[
+                PinTransaction::set(State::High),
+                PinTransaction::set(State::Low),
+                PinTransaction::set(State::High),
+            ]);
+            let mut max = Max31865::new(FailingSpi, ncs, PinMock::new(&[])).unwrap();
+            max.config = CONFIG_VBIAS;
+            let guard = PinMock::new(&[PinTransaction::set(asserted)]);
+            let mut reader = GuardedReader::new(max, guard, 10_000, polarity);
+
+            assert!(matches!(reader.read_guarded(), Err(Error::SPIError(()))));
+
+            let (mut max, mut guard) = reader.release();
+            max.ncs.done();
+            guard.done();
+        }
+    }
+}
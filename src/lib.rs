@@ -2,14 +2,51 @@
 //!
 //! # References
 //! - Datasheet: https://datasheets.maximintegrated.com/en/ds/MAX31865.pdf
+//!
+//! # Features
+//! - `std`: Enables the floating point API using the standard library.
+//! - `libm`: Enables the floating point API on `no_std` targets by using the
+//!   `libm` crate for the required math functions. One of `std` or `libm` is
+//!   required for the floating point API.
+//! - `heapless`: Enables formatting readings into `heapless` strings.
+//! - `fixed`: Enables reading temperatures as `fixed` crate fixed point
+//!   values.
+//! - `generated-table`: Generates high resolution lookup tables at build
+//!   time from the Callendar–Van Dusen equation, selected with the
+//!   `Pt100HighRes` and `Pt1000HighRes` curves. The step of the tables in
+//!   whole degrees is set with the `MAX31865_TABLE_STEP` environment variable
+//!   at build time, from `1` (the default, about 4 KiB of flash per table)
+//!   to `20`, e.g. `MAX31865_TABLE_STEP=5 cargo build --features
+//!   generated-table`. Smaller steps reduce the interpolation error at the
+//!   cost of flash.
+//! - `sim`: Enables the `testing` module with a simulated device for testing
+//!   applications without hardware.
+//! - `trace`: Enables `set_trace` for logging the raw SPI transactions.
+//!
+//! # Corrections
+//! All readings apply the configured corrections in the same fixed order:
+//!
+//! 1. The raw ADC code is converted to a resistance using the calibrated
+//!    reference resistance (`set_calibration`).
+//! 2. The lead resistance (`set_lead_resistance`) is subtracted, for two wire
+//!    sensors only.
+//! 3. The two-point gain and offset correction
+//!    (`set_two_point_calibration`) is applied to the resistance.
+//! 4. The resistance is converted to a temperature using the RTD curve.
+//! 5. The temperature offset (`set_temperature_offset` or `tare`) is added.
+//!
+//! `read_ohms` returns the resistance after step 3.
 
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![allow(clippy::doc_overindented_list_items)]
 
 extern crate embedded_hal as hal;
 
+use core::task::Poll;
+use hal::blocking::delay::DelayUs;
 use hal::blocking::spi;
 use hal::digital::v2::{InputPin, OutputPin};
-use hal::spi::{Mode, Phase, Polarity};
+use hal::spi::{FullDuplex, Mode, Phase, Polarity};
 
 #[cfg(feature = "doc")]
 pub mod examples;
@@ -19,38 +56,495 @@ pub const MODE: Mode = Mode {
     polarity: Polarity::IdleHigh,
 };
 
+/// The maximum SPI clock frequency supported by the MAX31865 in Hz.
+///
+/// The driver doesn't configure the SPI bus, so it is up to the application
+/// to stay below this. Exceeding it causes bit errors which show up as noisy
+/// or corrupt readings rather than errors, see `Max31865::check_bus_speed`.
+pub const MAX_SPI_HZ: u32 = 5_000_000;
+
+pub mod band;
+pub mod config;
+pub mod conversion;
+pub mod decimate;
+pub mod fault_log;
+pub mod guard;
+pub mod pipeline;
+pub mod sync;
 pub mod temp_conversion;
+#[cfg(feature = "sim")]
+pub mod testing;
+
+use conversion::Ohms;
+use pipeline::ReadingProcessor;
+use temp_conversion::{Celsius, LookupTable, RoundingMode, TempUnit};
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FilterMode {
     Filter60Hz = 0,
     Filter50Hz = 1,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SensorType {
     TwoOrFourWire = 0,
     ThreeWire = 1,
 }
 
-pub struct Max31865<SPI, NCS, RDY> {
+/// The number of wires used to connect the RTD.
+///
+/// Unlike `SensorType`, this distinguishes two from four wire sensors, which
+/// share the same configuration but differ in whether the lead resistance
+/// adds to the measured resistance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WireCount {
+    Two,
+    Three,
+    Four,
+}
+
+/// How the reference resistor and the RTD are wired to the MAX31865.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RefWiring {
+    /// The reference resistor is connected between REFIN+ and REFIN- and the
+    /// RTD between RTDIN+ and RTDIN-, as in the data sheet. The ADC code is
+    /// `R_RTD / R_REF * 2^15`.
+    #[default]
+    Standard,
+    /// The reference resistor and the RTD are swapped, i.e. the RTD is
+    /// measured as the reference and the reference resistor as the sense
+    /// resistance. The ADC code is `R_REF / R_RTD * 2^15`, so the RTD must
+    /// always be larger than the reference resistor. Resistances beyond the
+    /// lookup table of the RTD curve, i.e. codes close to `0`, are clamped to
+    /// the last entry of the table.
+    Swapped,
+}
+
+impl WireCount {
+    /// The sensor type configured in the device for this wire count.
+    pub fn sensor_type(&self) -> SensorType {
+        match self {
+            WireCount::Three => SensorType::ThreeWire,
+            WireCount::Two | WireCount::Four => SensorType::TwoOrFourWire,
+        }
+    }
+}
+
+/// The type of platinum RTD element connected to the MAX31865.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rtd {
+    /// A PT100 element with a nominal resistance of 100 Ohms at 0 C°.
+    Pt100,
+    /// A PT1000 element with a nominal resistance of 1000 Ohms at 0 C°.
+    Pt1000,
+}
+
+/// A resistance to temperature curve of an RTD element.
+///
+/// The driver is generic over the curve, so that the curve of a concrete type
+/// such as `Pt100` is resolved at compile time. The trait is object safe and
+/// implemented for references, so a driver can also hold a `DynCurve` to keep
+/// drivers for different sensors in one array or `Vec`.
+pub trait RtdCurve {
+    /// The lookup table used to convert resistance values for this RTD type.
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32>;
+
+    /// The nominal resistance at 0 C° in Ohms multiplied by 100.
+    fn nominal_resistance(&self) -> u32;
+
+    /// Convert a resistance into a temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohms_100` - The resistance in Ohms multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The output temperature is in degrees Celsius multiplied by 100. This
+    /// interpolates the lookup table, extrapolating outside of it.
+    fn resistance_to_temperature(&self, ohms_100: i32) -> i32 {
+        self.lookup_table().lookup_temperature(ohms_100)
+    }
+}
+
+/// An RTD curve selected at runtime, e.g. `&Pt1000 as DynCurve`.
+pub type DynCurve = &'static dyn RtdCurve;
+
+impl<T: RtdCurve + ?Sized> RtdCurve for &T {
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32> {
+        (**self).lookup_table()
+    }
+
+    fn nominal_resistance(&self) -> u32 {
+        (**self).nominal_resistance()
+    }
+
+    fn resistance_to_temperature(&self, ohms_100: i32) -> i32 {
+        (**self).resistance_to_temperature(ohms_100)
+    }
+}
+
+/// Marker type selecting the PT100 curve at compile time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pt100;
+
+/// Marker type selecting the PT1000 curve at compile time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pt1000;
+
+impl RtdCurve for Pt100 {
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32> {
+        &temp_conversion::LOOKUP_VEC_PT100
+    }
+
+    fn nominal_resistance(&self) -> u32 {
+        10_000
+    }
+}
+
+/// Marker type selecting the high resolution PT100 curve generated at build
+/// time. This requires the `generated-table` feature.
+#[cfg(feature = "generated-table")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pt100HighRes;
+
+/// Marker type selecting the high resolution PT1000 curve generated at build
+/// time. This requires the `generated-table` feature.
+#[cfg(feature = "generated-table")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pt1000HighRes;
+
+#[cfg(feature = "generated-table")]
+impl RtdCurve for Pt100HighRes {
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32> {
+        &temp_conversion::LOOKUP_VEC_PT100_GENERATED
+    }
+
+    fn nominal_resistance(&self) -> u32 {
+        10_000
+    }
+}
+
+#[cfg(feature = "generated-table")]
+impl RtdCurve for Pt1000HighRes {
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32> {
+        &temp_conversion::LOOKUP_VEC_PT1000_GENERATED
+    }
+
+    fn nominal_resistance(&self) -> u32 {
+        100_000
+    }
+}
+
+impl RtdCurve for Pt1000 {
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32> {
+        &temp_conversion::LOOKUP_VEC_PT1000
+    }
+
+    fn nominal_resistance(&self) -> u32 {
+        100_000
+    }
+}
+
+impl RtdCurve for Rtd {
+    fn lookup_table(&self) -> &'static LookupTable<'static, u32> {
+        match self {
+            Rtd::Pt100 => Pt100.lookup_table(),
+            Rtd::Pt1000 => Pt1000.lookup_table(),
+        }
+    }
+
+    fn nominal_resistance(&self) -> u32 {
+        match self {
+            Rtd::Pt100 => Pt100.nominal_resistance(),
+            Rtd::Pt1000 => Pt1000.nominal_resistance(),
+        }
+    }
+}
+
+impl Rtd {
+    /// The resistance the RTD is expected to have at a temperature, e.g. to
+    /// check the wiring against a multimeter reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius_100` - The temperature in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The output resistance is in Ohms multiplied by 100, e.g. 10972 for a
+    /// PT100 at 25 C°. This interpolates the same lookup table used to
+    /// convert readings, extrapolating outside of it, so it is the inverse of
+    /// the driver's conversion before any calibration or lead resistance
+    /// correction is applied.
+    pub fn resistance_at(&self, celsius_100: i32) -> u32 {
+        self.lookup_table().lookup_resistance(celsius_100).max(0) as u32
+    }
+
+    /// The first and last temperature covered by the lookup table of the RTD
+    /// in degrees Celsius multiplied by 100, e.g. to scale a gauge or reject
+    /// setpoints. See `LookupTable::temperature_range`.
+    pub fn temperature_range(&self) -> (i32, i32) {
+        self.lookup_table().temperature_range()
+    }
+
+    /// The sensitivity of the RTD at a temperature in Ohms multiplied by 100
+    /// per degree Celsius, e.g. `39` for a PT100 at 0 C°. See
+    /// `LookupTable::local_slope`.
+    pub fn local_slope(&self, celsius_100: i32) -> i32 {
+        self.lookup_table().local_slope(celsius_100)
+    }
+
+    /// The tolerance of the RTD at a temperature according to its tolerance
+    /// class.
+    ///
+    /// # Arguments
+    ///
+    /// * `class` - The tolerance class of the RTD element.
+    /// * `celsius_100` - The temperature in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the ± tolerance in hundredths of a degree, see
+    /// `ToleranceClass` for the formulas. The tolerance classes of IEC 60751
+    /// are the same for PT100 and PT1000 elements.
+    pub fn tolerance_band(&self, class: ToleranceClass, celsius_100: i32) -> u16 {
+        class.band(celsius_100)
+    }
+}
+
+/// The tolerance classes of IEC 60751.
+///
+/// The tolerance at a temperature `t` in degrees Celsius is:
+///
+/// - `AA`: ±(0.1 + 0.0017 |t|) C°
+/// - `A`: ±(0.15 + 0.002 |t|) C°
+/// - `B`: ±(0.3 + 0.005 |t|) C°
+/// - `C`: ±(0.6 + 0.01 |t|) C°
+///
+/// The standard only specifies each class over a limited temperature range,
+/// which depends on the construction of the element, see its data sheet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToleranceClass {
+    AA,
+    A,
+    B,
+    C,
+}
+
+impl ToleranceClass {
+    /// The ± tolerance at a temperature in degrees Celsius multiplied by 100,
+    /// in hundredths of a degree rounded to the nearest hundredth.
+    pub fn band(&self, celsius_100: i32) -> u16 {
+        // The constant term in hundredths and the slope in units of 1/10000.
+        let (base, slope) = match self {
+            ToleranceClass::AA => (10, 17),
+            ToleranceClass::A => (15, 20),
+            ToleranceClass::B => (30, 50),
+            ToleranceClass::C => (60, 100),
+        };
+        let band = base + (celsius_100.unsigned_abs() * slope + 5_000) / 10_000;
+
+        band.min(u16::MAX as u32) as u16
+    }
+}
+
+/// A monotonic tick source supplied by the application.
+///
+/// The unit of the ticks is up to the application, e.g. milliseconds. The
+/// tick count may wrap around. This is implemented for closures returning the
+/// current tick count.
+pub trait Clock {
+    /// The current tick count.
+    fn now(&mut self) -> u32;
+}
+
+impl<F: FnMut() -> u32> Clock for F {
+    fn now(&mut self) -> u32 {
+        self()
+    }
+}
+
+/// The contents of the fault status register.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FaultStatus(pub u8);
+
+impl FaultStatus {
+    /// Whether any fault is flagged.
+    pub fn is_fault(&self) -> bool {
+        self.0 & 0xFC != 0
+    }
+
+    /// D7: The RTD resistance is above the high fault threshold.
+    pub fn high_threshold(&self) -> bool {
+        self.0 & FAULT_HIGH_THRESHOLD != 0
+    }
+
+    /// D6: The RTD resistance is below the low fault threshold.
+    pub fn low_threshold(&self) -> bool {
+        self.0 & FAULT_LOW_THRESHOLD != 0
+    }
+
+    /// D5: REFIN- is above 0.85 x V_BIAS.
+    pub fn refin_high(&self) -> bool {
+        self.0 & (1 << 5) != 0
+    }
+
+    /// D4: REFIN- is below 0.85 x V_BIAS (FORCE- open).
+    pub fn refin_low(&self) -> bool {
+        self.0 & (1 << 4) != 0
+    }
+
+    /// D3: RTDIN- is below 0.85 x V_BIAS (FORCE- open).
+    pub fn rtdin_low(&self) -> bool {
+        self.0 & (1 << 3) != 0
+    }
+
+    /// D2: Overvoltage or undervoltage on an input.
+    pub fn over_under_voltage(&self) -> bool {
+        self.0 & (1 << 2) != 0
+    }
+}
+
+/// A closure receiving the bytes sent and received in an SPI transaction,
+/// see `Max31865::set_trace`.
+#[cfg(feature = "trace")]
+pub type TraceFn = &'static mut (dyn FnMut(&[u8], &[u8]) + Send);
+
+pub struct Max31865<SPI, NCS, RDY, C = Pt100> {
     spi: SPI,
     ncs: NCS,
     rdy: RDY,
+    curve: C,
+    transfer: Option<NbTransfer>,
+    config: u8,
+    bias_settle_us: u32,
+    sleep_config: Option<u8>,
+    wire_count: WireCount,
+    reference_resistor: u32,
     calibration: u32,
+    lead_resistance: u32,
+    reference_wiring: RefWiring,
+    two_point: Option<[(u32, u32); 2]>,
+    calibrated_range: Option<(i32, i32)>,
+    offset: i32,
+    fault_retries: u8,
+    bias_check_interval: u8,
+    reads_since_bias_check: u8,
+    ready_fallback: bool,
+    filter_auto_switch: bool,
+    ready_timed_out: bool,
+    ready_level: bool,
+    ready_tick: Option<u32>,
+    cache: Option<(i32, u32)>,
+    baseline: Option<i32>,
+    last_accepted: Option<i32>,
+    average: pipeline::Smoothing,
+    stats: DeviceStats,
+    #[cfg(feature = "trace")]
+    trace: Option<TraceFn>,
+}
+
+/// A temperature reading from a blocking read, see `Max31865::read_blocking`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reading {
+    /// The temperature in degrees Celsius multiplied by 100.
+    pub temperature: i32,
+    /// Set if the ready pin was never asserted and the registers were read
+    /// anyway, see `Max31865::set_ready_fallback`. The reading may then be
+    /// from an incomplete or stale conversion.
+    pub unconfirmed: bool,
+}
+
+/// The result of `Max31865::detect_mains_interference`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MainsEstimate {
+    /// The mean change between consecutive ADC codes with the 50Hz filter.
+    pub ripple_50hz: u32,
+    /// The mean change between consecutive ADC codes with the 60Hz filter.
+    pub ripple_60hz: u32,
+    /// The filter with clearly less ripple, or `None` if both filters
+    /// perform the same within the ADC noise.
+    pub recommended: Option<FilterMode>,
+    /// The filter configured when the detection finished.
+    pub settled: FilterMode,
+    /// Set if the filter was switched to the recommended one, see
+    /// `Max31865::set_filter_auto_switch`.
+    pub switched: bool,
+}
+
+/// A temperature reading together with the threshold faults of the device,
+/// see `Max31865::read_and_check_thresholds`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThresholdResult {
+    /// The temperature in degrees Celsius multiplied by 100.
+    pub temperature: i32,
+    /// Set if the high fault threshold was exceeded.
+    pub high_tripped: bool,
+    /// Set if the reading was below the low fault threshold.
+    pub low_tripped: bool,
+}
+
+/// Counters of the driver's activity, see `Max31865::stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeviceStats {
+    /// The number of conversions read, including faulted ones.
+    pub conversions: u32,
+    /// The number of conversions read with the fault bit set.
+    pub faults: u32,
+    /// The number of SPI transfers that failed.
+    pub spi_errors: u32,
 }
 
 #[derive(Debug)]
 pub enum Error<E> {
     SPIError(E),
     PinError,
+    Timeout,
+    InvalidCalibration,
+    SuspiciousReading,
+    ConversionFault,
+    NoReadPending,
+    IncompleteTransfer,
+    BufferTooSmall,
+    NoBaseline,
+    CalibrationOutOfRange,
+    BusSpeedSuspect,
+    BiasDisabled,
+    ReferenceMismatch,
+    RateExceeded,
+    OutOfSensorRange,
+}
+
+impl<E, SPI, NCS, RDY> Max31865<SPI, NCS, RDY, Pt100>
+where
+    SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    RDY: InputPin,
+{
+    /// Create a new MAX31865 module for a PT100 sensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `spi` - The SPI module to communicate on.
+    /// * `ncs` - The chip select pin which should be set to a push pull output
+    ///           pin.
+    /// * `rdy` - The ready pin which is set low by the MAX31865 controller
+    ///           whenever it has finished converting the output.
+    ///
+    pub fn new(spi: SPI, ncs: NCS, rdy: RDY) -> Result<Max31865<SPI, NCS, RDY, Pt100>, Error<E>> {
+        Self::new_with_curve(spi, ncs, rdy, Pt100)
+    }
 }
 
-impl<E, SPI, NCS, RDY> Max31865<SPI, NCS, RDY>
+impl<E, SPI, NCS, RDY, C> Max31865<SPI, NCS, RDY, C>
 where
     SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E>,
     NCS: OutputPin,
     RDY: InputPin,
+    C: RtdCurve,
 {
-    /// Create a new MAX31865 module.
+    /// Create a new MAX31865 module for the given RTD type.
     ///
     /// # Arguments
     ///
@@ -59,16 +553,50 @@ where
     ///           pin.
     /// * `rdy` - The ready pin which is set low by the MAX31865 controller
     ///           whenever it has finished converting the output.
+    /// * `curve` - The RTD curve used by `read_default_conversion`, e.g.
+    ///             `Pt1000`.
     ///
-    pub fn new(spi: SPI, mut ncs: NCS, rdy: RDY) -> Result<Max31865<SPI, NCS, RDY>, Error<E>> {
-        let default_calib = 40000;
+    pub fn new_with_curve(
+        spi: SPI,
+        mut ncs: NCS,
+        rdy: RDY,
+        curve: C,
+    ) -> Result<Max31865<SPI, NCS, RDY, C>, Error<E>> {
+        let default_reference = 40000;
 
         ncs.set_high().map_err(|_| Error::PinError)?;
         let max31865 = Max31865 {
             spi,
             ncs,
             rdy,
-            calibration: default_calib, /* value in ohms multiplied by 100 */
+            curve,
+            transfer: None,
+            config: 0,
+            bias_settle_us: DEFAULT_BIAS_SETTLE_US,
+            sleep_config: None,
+            wire_count: WireCount::Two,
+            reference_resistor: default_reference, /* value in ohms multiplied by 100 */
+            calibration: default_reference,
+            lead_resistance: 0,
+            reference_wiring: RefWiring::Standard,
+            two_point: None,
+            calibrated_range: None,
+            offset: 0,
+            fault_retries: 1,
+            bias_check_interval: 0,
+            reads_since_bias_check: 0,
+            ready_fallback: false,
+            filter_auto_switch: false,
+            ready_timed_out: false,
+            ready_level: false,
+            ready_tick: None,
+            cache: None,
+            baseline: None,
+            last_accepted: None,
+            average: pipeline::Smoothing::new(DEFAULT_AVERAGE_SHIFT),
+            stats: DeviceStats::default(),
+            #[cfg(feature = "trace")]
+            trace: None,
         };
 
         Ok(max31865)
@@ -104,134 +632,5289 @@ where
         sensor_type: SensorType,
         filter_mode: FilterMode,
     ) -> Result<(), Error<E>> {
-        let conf: u8 = ((vbias as u8) << 7)
-            | ((conversion_mode as u8) << 6)
-            | ((one_shot as u8) << 5)
-            | ((sensor_type as u8) << 4)
-            | (filter_mode as u8);
+        let conf = config::Config::new()
+            .vbias(vbias)
+            .conversion_mode(conversion_mode)
+            .sensor_type(sensor_type)
+            .filter_mode(filter_mode)
+            .to_register()
+            | ((one_shot as u8) << 5);
 
         self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+        self.wire_count = match (sensor_type, self.wire_count) {
+            (SensorType::ThreeWire, _) => WireCount::Three,
+            (SensorType::TwoOrFourWire, WireCount::Four) => WireCount::Four,
+            (SensorType::TwoOrFourWire, _) => WireCount::Two,
+        };
 
         Ok(())
     }
 
-    /// Set the calibration reference resistance. This can be used to calibrate
-    /// inaccuracies of both the reference resistor and the PT100 element.
+    /// Update the configuration like `configure`, returning the previous
+    /// configuration.
+    ///
+    /// # Remarks
+    ///
+    /// The arguments are the same as for `configure`. The configuration
+    /// register is read before it is written, and its previous value is
+    /// returned so that it can be restored with `write_config_raw` after a
+    /// temporary change, e.g. switching to a one-shot conversion for a single
+    /// precise reading before returning to automatic conversion mode.
+    pub fn configure_returning_previous(
+        &mut self,
+        vbias: bool,
+        conversion_mode: bool,
+        one_shot: bool,
+        sensor_type: SensorType,
+        filter_mode: FilterMode,
+    ) -> Result<u8, Error<E>> {
+        let previous = self.read(Register::CONFIG)?;
+        self.configure(vbias, conversion_mode, one_shot, sensor_type, filter_mode)?;
+
+        Ok(previous)
+    }
+
+    /// Apply a configuration built with `config::Config`.
     ///
     /// # Arguments
     ///
-    /// * `calib` - A 32 bit integer specifying the reference resistance in ohms
-    ///             multiplied by 100, e.g. `40000` for 400 Ohms
+    /// * `config` - The configuration to write to the device.
     ///
     /// # Remarks
     ///
-    /// You can perform calibration by putting the sensor in boiling (100
-    /// degrees Celsius) water and then measuring the raw value using
-    /// `read_raw`. Calculate `calib` as `(13851 << 15) / raw >> 1`.
-    pub fn set_calibration(&mut self, calib: u32) -> () {
-        self.calibration = calib;
+    /// This is equivalent to calling `configure` with the settings of the
+    /// builder and without triggering a one-shot conversion.
+    pub fn apply_config(&mut self, config: config::Config) -> Result<(), Error<E>> {
+        let (vbias, conversion_mode, sensor_type, filter_mode) = config.parts();
+
+        self.configure(vbias, conversion_mode, false, sensor_type, filter_mode)
     }
 
-    /// Read the raw resistance value.
+    /// Read the configuration of the device into a builder.
     ///
     /// # Remarks
     ///
-    /// The output value is the value in Ohms multiplied by 100.
-    pub fn read_ohms(&mut self) -> Result<u32, Error<E>> {
-        let raw = self.read_raw()?;
-        let ohms = ((raw >> 1) as u32 * self.calibration) >> 15;
+    /// The builder is populated from the configuration register, so a single
+    /// setting can be changed, e.g. with `filter_50hz`, and the result written
+    /// back with `apply_config`. The command bits
+    /// of the register (one-shot, fault detection cycle and fault status
+    /// clear) are not part of the builder.
+    pub fn read_config(&mut self) -> Result<config::Config, Error<E>> {
+        let conf = self.read(Register::CONFIG)?;
 
-        Ok(ohms)
+        Ok(config::Config::from_register(conf))
     }
 
-    /// Read the raw resistance value and then perform conversion to degrees Celsius.
+    /// Set the number of wires used to connect the RTD.
+    ///
+    /// # Arguments
+    ///
+    /// * `wire_count` - The number of wires of the sensor.
     ///
     /// # Remarks
     ///
-    /// The output value is the value in degrees Celsius multiplied by 100.
-    pub fn read_default_conversion(&mut self) -> Result<i32, Error<E>> {
-        let ohms = self.read_ohms()?;
-        let temp = temp_conversion::LOOKUP_VEC_PT100.lookup_temperature(ohms as i32);
+    /// This updates the 3-wire bit of the configuration register, leaving the
+    /// rest of the configuration untouched. The lead resistance set with
+    /// `set_lead_resistance` is only subtracted for two wire sensors, as
+    /// three and four wire connections compensate for it in hardware.
+    ///
+    /// `configure` can't distinguish two and four wire sensors, so calling it
+    /// with `SensorType::TwoOrFourWire` keeps `WireCount::Four` if it was set
+    /// and otherwise assumes `WireCount::Two`.
+    pub fn set_wire_count(&mut self, wire_count: WireCount) -> Result<(), Error<E>> {
+        let conf = self.read(Register::CONFIG)? & !CONFIG_3WIRE;
+        let conf = conf | ((wire_count.sensor_type() as u8) << 4);
 
-        Ok(temp)
+        self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+        self.wire_count = wire_count;
+
+        Ok(())
     }
 
-    /// Read the raw RTD value.
+    /// Write the configuration register verbatim.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The value of the configuration register:
+    ///   - D7: V_BIAS (1 = on)
+    ///   - D6: Conversion mode (1 = auto, 0 = normally off)
+    ///   - D5: 1-shot (1 = start a single conversion, auto-clear)
+    ///   - D4: 3-wire (1 = 3-wire RTD, 0 = 2-wire or 4-wire)
+    ///   - D3, D2: Fault detection cycle control
+    ///   - D1: Fault status clear (1 = clear, auto-clear)
+    ///   - D0: 50/60Hz filter select (1 = 50Hz, 0 = 60Hz)
     ///
     /// # Remarks
     ///
-    /// The raw value is the value of the combined MSB and LSB registers.
-    /// The first 15 bits specify the ohmic value in relation to the reference
-    /// resistor (i.e. 2^15 - 1 would be the exact same resistance as the reference
-    /// resistor). See manual for further information.
-    /// The last bit specifies if the conversion was successful.
-    pub fn read_raw(&mut self) -> Result<u16, Error<E>> {
-        let msb: u16 = self.read(Register::RTD_MSB)? as u16;
-        let lsb: u16 = self.read(Register::RTD_LSB)? as u16;
+    /// This bypasses `configure`, e.g. to replay a configuration byte
+    /// captured from a known good unit. See the datasheet for the meaning of
+    /// the individual bits.
+    pub fn write_config_raw(&mut self, config: u8) -> Result<(), Error<E>> {
+        self.write(Register::CONFIG, config)?;
+        self.config = config;
 
-        Ok((msb << 8) | lsb)
+        Ok(())
     }
 
-    /// Determine if a new conversion is available
+    /// Read the configuration register verbatim.
     ///
     /// # Remarks
     ///
-    /// When the module is finished converting the temperature it sets the
-    /// ready pin to low. It is automatically returned to high upon reading the
-    /// RTD registers.
-    pub fn is_ready(&self) -> Result<bool, RDY::Error> {
-        self.rdy.is_low()
+    /// See `write_config_raw` for the meaning of the individual bits.
+    pub fn read_config_raw(&mut self) -> Result<u8, Error<E>> {
+        self.read(Register::CONFIG)
     }
 
-    fn read(&mut self, reg: Register) -> Result<u8, Error<E>> {
-        let buffer: [u8; 2] = self.read_two(reg)?;
-        Ok(buffer[1])
+    /// Set the time to wait after enabling V_BIAS before starting a
+    /// conversion.
+    ///
+    /// # Arguments
+    ///
+    /// * `us` - The settling time in microseconds.
+    ///
+    /// # Remarks
+    ///
+    /// The required time depends on the RC filter on the RTD inputs of the
+    /// board, see the datasheet. It is used by `enable_bias` and
+    /// `read_one_shot_low_power` and defaults to `DEFAULT_BIAS_SETTLE_US`
+    /// (10ms), the datasheet value for the recommended filter.
+    pub fn set_bias_settle_time_us(&mut self, us: u32) {
+        self.bias_settle_us = us;
     }
 
-    fn read_two(&mut self, reg: Register) -> Result<[u8; 2], Error<E>> {
-        let mut buffer = [0u8; 2];
-        let slice: &mut [u8] = &mut buffer;
-        slice[0] = reg.read_address();
-        self.ncs.set_low().map_err(|_| Error::PinError)?;
-        self.spi.transfer(slice).map_err(|e| Error::SPIError(e))?;
-        self.ncs.set_high().map_err(|_| Error::PinError)?;
+    /// The time waited after enabling V_BIAS in microseconds, see
+    /// `set_bias_settle_time_us`.
+    pub fn bias_settle_time_us(&self) -> u32 {
+        self.bias_settle_us
+    }
+
+    /// Enable V_BIAS and wait for it to settle.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the bias voltage to
+    ///             settle.
+    ///
+    /// # Remarks
+    ///
+    /// This leaves the rest of the configuration untouched and waits for the
+    /// time set with `set_bias_settle_time_us`.
+    pub fn enable_bias(&mut self, delay: &mut impl DelayUs<u32>) -> Result<(), Error<E>> {
+        let conf = self.read(Register::CONFIG)? | CONFIG_VBIAS;
+        self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+        delay.delay_us(self.bias_settle_us);
 
-        Ok(buffer)
+        Ok(())
     }
 
-    fn write(&mut self, reg: Register, val: u8) -> Result<(), Error<E>> {
-        self.ncs.set_low().map_err(|_| Error::PinError)?;
-        self.spi
-            .write(&[reg.write_address(), val])
-            .map_err(|e| Error::SPIError(e))?;
-        self.ncs.set_high().map_err(|_| Error::PinError)?;
+    /// Record the readings after enabling V_BIAS to choose the bias settling
+    /// time.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait between readings.
+    /// * `samples` - The number of readings to record.
+    /// * `interval_us` - The time between readings in microseconds.
+    /// * `out` - The buffer for the readings in degrees Celsius multiplied by
+    ///           100, at least `samples` long.
+    ///
+    /// # Remarks
+    ///
+    /// This disables and then re-enables V_BIAS, leaving the rest of the
+    /// configuration untouched, and reads the temperature every
+    /// `interval_us` microseconds, starting one interval after V_BIAS was
+    /// enabled. The readings approach the true temperature as the input
+    /// filter capacitor charges; the time after which they are stable is a
+    /// suitable value for `set_bias_settle_time_us`. The device should be in
+    /// automatic conversion mode, and intervals shorter than the conversion
+    /// period (about 20 ms) return the same conversion several times.
+    ///
+    /// Returns `Error::BufferTooSmall` without changing the configuration if
+    /// `out` is shorter than `samples`.
+    ///
+    /// *Note*: This is a tool for bring-up and characterization of a board,
+    /// it blocks for `samples` intervals and is not meant for regular use.
+    pub fn profile_settling(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        samples: usize,
+        interval_us: u32,
+        out: &mut [i32],
+    ) -> Result<(), Error<E>> {
+        if out.len() < samples {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let conf = self.read(Register::CONFIG)? & !CONFIG_ONE_SHOT;
+        self.write(Register::CONFIG, conf & !CONFIG_VBIAS)?;
+        self.write(Register::CONFIG, conf | CONFIG_VBIAS)?;
+        self.config = conf | CONFIG_VBIAS;
+
+        for sample in &mut out[..samples] {
+            delay.delay_us(interval_us);
+            *sample = self.read_default_conversion()?;
+        }
+
         Ok(())
     }
-}
 
-#[allow(non_camel_case_types)]
-#[allow(dead_code)]
-#[derive(Clone, Copy)]
-enum Register {
-    CONFIG = 0x00,
-    RTD_MSB = 0x01,
-    RTD_LSB = 0x02,
-    HIGH_FAULT_THRESHOLD_MSB = 0x03,
-    HIGH_FAULT_THRESHOLD_LSB = 0x04,
-    LOW_FAULT_THRESHOLD_MSB = 0x05,
-    LOW_FAULT_THRESHOLD_LSB = 0x06,
-    FAULT_STATUS = 0x07,
-}
+    /// Enable V_BIAS and wait for the self-heating of the RTD to settle.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait between readings.
+    /// * `max_wait_ms` - The maximum time to wait for the readings to
+    ///                   stabilize in milliseconds.
+    ///
+    /// # Remarks
+    ///
+    /// The bias current heats the RTD, so the readings drift upwards for a
+    /// few seconds after V_BIAS is enabled. This enables V_BIAS (see
+    /// `enable_bias`) and then reads the temperature every
+    /// `WARM_UP_INTERVAL_MS` until two successive readings differ by at most
+    /// `WARM_UP_THRESHOLD`, or `max_wait_ms` has elapsed.
+    ///
+    /// Returns `true` if the readings stabilized and `false` if the time ran
+    /// out first. The device should be in automatic conversion mode.
+    pub fn warm_up(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        max_wait_ms: u32,
+    ) -> Result<bool, Error<E>> {
+        self.enable_bias(delay)?;
 
-const R: u8 = 0 << 7;
-const W: u8 = 1 << 7;
+        let mut previous = self.read_default_conversion()?;
+        let mut waited_ms = 0;
 
-impl Register {
-    fn read_address(&self) -> u8 {
-        *self as u8 | R
+        while waited_ms < max_wait_ms {
+            delay.delay_us(WARM_UP_INTERVAL_MS * 1_000);
+            waited_ms += WARM_UP_INTERVAL_MS;
+
+            let temp = self.read_default_conversion()?;
+            if (temp - previous).abs() <= WARM_UP_THRESHOLD {
+                return Ok(true);
+            }
+            previous = temp;
+        }
+
+        Ok(false)
     }
 
-    fn write_address(&self) -> u8 {
+    /// Disable V_BIAS to reduce power consumption.
+    ///
+    /// # Remarks
+    ///
+    /// This leaves the rest of the configuration untouched.
+    pub fn disable_bias(&mut self) -> Result<(), Error<E>> {
+        let conf = self.read(Register::CONFIG)? & !CONFIG_VBIAS;
+        self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+
+        Ok(())
+    }
+
+    /// Power down the analog front end between infrequent samples.
+    ///
+    /// # Remarks
+    ///
+    /// This disables V_BIAS and automatic conversion mode, leaving the rest of
+    /// the configuration untouched, and stores the previous configuration for
+    /// `wake`. Calling this while already asleep has no effect.
+    ///
+    /// Disabling V_BIAS removes the bias current through the reference
+    /// resistor and the RTD of about 2 V / (R_REF + R_RTD), i.e. around 4 mA
+    /// for a PT100 with a 400 Ohm reference and 0.4 mA for a PT1000 with a
+    /// 4000 Ohm reference. The supply current of the MAX31865 itself remains,
+    /// as the device has no shutdown mode.
+    pub fn sleep(&mut self) -> Result<(), Error<E>> {
+        if self.sleep_config.is_some() {
+            return Ok(());
+        }
+
+        let conf = self.read(Register::CONFIG)? & !CONFIG_ONE_SHOT;
+        let sleeping = conf & !(CONFIG_VBIAS | CONFIG_CONVERSION_MODE);
+        self.write(Register::CONFIG, sleeping)?;
+        self.config = sleeping;
+        self.sleep_config = Some(conf);
+
+        Ok(())
+    }
+
+    /// Restore the configuration from before `sleep`.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the bias voltage to
+    ///             settle.
+    ///
+    /// # Remarks
+    ///
+    /// Writes back the configuration stored by `sleep` and, if V_BIAS was
+    /// enabled, waits for the time set with `set_bias_settle_time_us`. Does
+    /// nothing if the device is not asleep.
+    pub fn wake(&mut self, delay: &mut impl DelayUs<u32>) -> Result<(), Error<E>> {
+        if let Some(conf) = self.sleep_config {
+            self.write(Register::CONFIG, conf)?;
+            self.config = conf;
+            self.sleep_config = None;
+
+            if conf & CONFIG_VBIAS != 0 {
+                delay.delay_us(self.bias_settle_us);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Perform a single conversion with V_BIAS only enabled for its duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the bias voltage to
+    ///             settle and the conversion to complete.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// This switches to normally off mode, enables V_BIAS and waits for the
+    /// time set with `set_bias_settle_time_us`, triggers a one-shot
+    /// conversion, waits for it to complete and finally disables V_BIAS
+    /// again. This minimizes the power dissipated in the RTD and reference
+    /// resistor when sampling infrequently.
+    pub fn read_one_shot_low_power(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<i32, Error<E>> {
+        let conf = self.read(Register::CONFIG)? & !(CONFIG_VBIAS | CONFIG_CONVERSION_MODE);
+
+        self.write(Register::CONFIG, conf | CONFIG_VBIAS)?;
+        self.config = conf | CONFIG_VBIAS;
+        delay.delay_us(self.bias_settle_us);
+        self.write(Register::CONFIG, conf | CONFIG_VBIAS | CONFIG_ONE_SHOT)?;
+
+        let temp = self
+            .wait_for_ready(delay, CONVERSION_TIMEOUT_MS)
+            .and_then(|_| self.read_default_conversion());
+
+        self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+
+        temp
+    }
+
+    /// Take a single one-shot reading from automatic conversion mode and pass
+    /// it to `f`.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the bias voltage to
+    ///             settle and the conversion to complete.
+    /// * `f` - Called with the temperature in degrees Celsius multiplied by
+    ///         100.
+    ///
+    /// # Remarks
+    ///
+    /// This saves the configuration, switches to normally off mode with
+    /// V_BIAS enabled, triggers a one-shot conversion and reads it. The saved
+    /// configuration is restored before `f` is called, so the device is back
+    /// in automatic conversion mode even if `f` panics. If the reading fails
+    /// the configuration is restored as well and the error is returned.
+    ///
+    /// The mode switch costs the one-shot conversion time (up to 66 ms with
+    /// the 50Hz filter), plus the V_BIAS settling time (see
+    /// `set_bias_settle_time_us`) if V_BIAS was disabled. After restoring,
+    /// the next automatic conversion completes one conversion period later.
+    pub fn with_oneshot<R>(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        f: impl FnOnce(i32) -> R,
+    ) -> Result<R, Error<E>> {
+        let saved = self.read(Register::CONFIG)? & !CONFIG_ONE_SHOT;
+        let conf = (saved & !CONFIG_CONVERSION_MODE) | CONFIG_VBIAS;
+
+        self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+        if saved & CONFIG_VBIAS == 0 {
+            delay.delay_us(self.bias_settle_us);
+        }
+
+        let temp = self
+            .write(Register::CONFIG, conf | CONFIG_ONE_SHOT)
+            .and_then(|_| self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS))
+            .and_then(|_| self.read_default_conversion());
+
+        self.write(Register::CONFIG, saved)?;
+        self.config = saved;
+
+        Ok(f(temp?))
+    }
+
+    /// Perform a one-shot conversion with V_BIAS disabled and read the raw
+    /// RTD value.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the conversion to
+    ///             complete and the bias voltage to settle afterwards.
+    ///
+    /// # Remarks
+    ///
+    /// The raw value is the value of the combined MSB and LSB registers, as
+    /// for `read_raw`. This switches to normally off mode with V_BIAS
+    /// disabled, triggers a one-shot conversion, reads it and restores the
+    /// previous configuration. If V_BIAS was enabled before, this then waits
+    /// for the time set with `set_bias_settle_time_us`.
+    ///
+    /// Without the bias voltage no current flows through the RTD, so on a
+    /// healthy board the ADC code (`raw >> 1`) is zero or within a few LSB
+    /// of it. A code significantly above that indicates leakage into the
+    /// RTD inputs or an input offset of the ADC. The fault bit is likely set,
+    /// as the input voltages are out of range without the bias voltage.
+    ///
+    /// *Note*: This is a diagnostic for board bring-up, not a measurement.
+    /// The result is not a temperature or resistance and no corrections are
+    /// applied.
+    pub fn read_raw_no_bias(&mut self, delay: &mut impl DelayUs<u32>) -> Result<u16, Error<E>> {
+        let saved = self.read(Register::CONFIG)? & !CONFIG_ONE_SHOT;
+        let conf = saved & !(CONFIG_VBIAS | CONFIG_CONVERSION_MODE);
+
+        self.write(Register::CONFIG, conf)?;
+        self.config = conf;
+
+        let mut bytes = [0u8; 2];
+        let raw = self
+            .write(Register::CONFIG, conf | CONFIG_ONE_SHOT)
+            .and_then(|_| self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS))
+            .and_then(|_| self.read_burst(Register::RTD_MSB, 2, &mut bytes));
+
+        self.write(Register::CONFIG, saved)?;
+        self.config = saved;
+        if saved & CONFIG_VBIAS != 0 {
+            delay.delay_us(self.bias_settle_us);
+        }
+
+        raw?;
+        Ok(u16::from_be_bytes(bytes))
+    }
+
+    /// Run `f` with the fault detection cycle bits cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure performing the measurement, called with the
+    ///         driver.
+    ///
+    /// # Remarks
+    ///
+    /// This clears the fault detection cycle control bits (D3:D2) of the
+    /// configuration register, runs `f` and then restores the previous
+    /// configuration, regardless of whether `f` succeeded. The self-clearing
+    /// one-shot and fault status clear bits are not restored, so neither a
+    /// conversion nor a fault clear is triggered again. This provides a
+    /// measurement window free of fault detection cycles. Faults that would
+    /// have been detected during the window are not latched in the fault
+    /// status register.
+    ///
+    /// If `f` fails its error is returned, otherwise the error of restoring
+    /// the configuration, if any.
+    pub fn with_fault_detection_disabled<F, R>(&mut self, f: F) -> Result<R, Error<E>>
+    where
+        F: FnOnce(&mut Self) -> Result<R, Error<E>>,
+    {
+        let conf = self.read(Register::CONFIG)? & !(CONFIG_ONE_SHOT | CONFIG_FAULT_CLEAR);
+        self.write(Register::CONFIG, conf & !CONFIG_FAULT_DETECTION)?;
+        self.config = conf & !CONFIG_FAULT_DETECTION;
+
+        let result = f(self);
+        let restored = self.write(Register::CONFIG, conf);
+        if restored.is_ok() {
+            self.config = conf;
+        }
+
+        let result = result?;
+        restored?;
+
+        Ok(result)
+    }
+
+    /// Read back the configured sensor wiring from the device.
+    ///
+    /// # Remarks
+    ///
+    /// This reads the configuration register, so it reflects the actual
+    /// device state, e.g. after the device has been reset.
+    pub fn sensor_type(&mut self) -> Result<SensorType, Error<E>> {
+        let conf = self.read(Register::CONFIG)?;
+
+        if conf & CONFIG_3WIRE != 0 {
+            Ok(SensorType::ThreeWire)
+        } else {
+            Ok(SensorType::TwoOrFourWire)
+        }
+    }
+
+    /// Change the mains frequency filter without disturbing the rest of the
+    /// configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter_mode` - Specify the mains frequency that should be used to
+    ///                   filter out noise, e.g. 50Hz in Europe.
+    /// * `delay` - A delay provider used to let the device settle between
+    ///             configuration changes.
+    ///
+    /// # Remarks
+    ///
+    /// The datasheet requires that the filter is only changed while automatic
+    /// conversion mode is off. This performs the following sequence:
+    ///
+    /// 1. Read the configuration register.
+    /// 2. If automatic conversion is enabled, clear it and wait for any
+    ///    conversion in progress to finish.
+    /// 3. Write the new filter mode.
+    /// 4. If automatic conversion was enabled, wait again and then restore it.
+    pub fn set_filter_mode(
+        &mut self,
+        filter_mode: FilterMode,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<(), Error<E>> {
+        let conf = self.read(Register::CONFIG)?;
+        let auto = conf & CONFIG_CONVERSION_MODE;
+
+        let mut conf = conf & !CONFIG_CONVERSION_MODE;
+        if auto != 0 {
+            self.write(Register::CONFIG, conf)?;
+            delay.delay_us(CONVERSION_SETTLE_US);
+        }
+
+        conf = (conf & !CONFIG_FILTER_MODE) | (filter_mode as u8);
+        self.write(Register::CONFIG, conf)?;
+
+        if auto != 0 {
+            delay.delay_us(CONVERSION_SETTLE_US);
+            self.write(Register::CONFIG, conf | auto)?;
+        }
+        self.config = conf | auto;
+
+        Ok(())
+    }
+
+    /// Set the nominal value of the reference resistor fitted on the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohms_100` - The reference resistance in ohms multiplied by 100, e.g.
+    ///                `43000` for 430 Ohms.
+    ///
+    /// # Remarks
+    ///
+    /// This also resets the calibration to the nominal reference resistance.
+    /// Use `set_calibration` afterwards to correct for inaccuracies.
+    pub fn set_reference_resistor(&mut self, ohms_100: u32) {
+        self.reference_resistor = ohms_100;
+        self.calibration = ohms_100;
+    }
+
+    /// Set the nominal value of the reference resistor fitted on the board in
+    /// Ohms.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohms` - The reference resistance in Ohms, e.g. `430.0`.
+    ///
+    /// # Remarks
+    ///
+    /// This is the same as `set_reference_resistor`, which stores the
+    /// resistance in Ohms multiplied by 100. The value is therefore rounded to
+    /// the nearest 0.01 Ohm, and negative values are treated as `0`. An `f32`
+    /// is precise to 0.01 Ohm for resistances of up to about 80 kOhm.
+    ///
+    /// *Note*: This requires either the `std` or the `libm` feature.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn set_reference_resistor_f32(&mut self, ohms: f32) {
+        self.set_reference_resistor((ohms * 100.0 + 0.5) as u32);
+    }
+
+    /// The nominal reference resistance in ohms multiplied by 100, as set by
+    /// `set_reference_resistor`. Defaults to `40000` (400 Ohms).
+    pub fn reference_resistor(&self) -> u32 {
+        self.reference_resistor
+    }
+
+    /// Whether the reference resistor suits an RTD type.
+    ///
+    /// # Arguments
+    ///
+    /// * `rtd` - The RTD type to check the reference resistor against.
+    ///
+    /// # Remarks
+    ///
+    /// The reference resistor should be about four times the nominal
+    /// resistance of the RTD, e.g. 400 or 430 Ohms for a PT100 and 4000 or
+    /// 4300 Ohms for a PT1000. A smaller reference saturates the ADC at high
+    /// temperatures (a PT100 reaches 390 Ohms at 850 C°), a larger one wastes
+    /// resolution. This returns `true` if the reference resistor is between
+    /// `REFERENCE_RATIO_MIN` and `REFERENCE_RATIO_MAX` times the nominal
+    /// resistance, which catches a reference fitted for the other RTD type.
+    pub fn validate_reference_for(&self, rtd: Rtd) -> bool {
+        reference_matches(self.reference_resistor, rtd.nominal_resistance())
+    }
+
+    /// Check the reference resistor against the RTD curve of the driver.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `Error::ReferenceMismatch` if the reference resistor does not
+    /// suit the nominal resistance of the curve, see
+    /// `validate_reference_for`. Call this during initialisation after
+    /// `set_reference_resistor`, as the default reference of 400 Ohms only
+    /// suits a PT100.
+    pub fn check_reference(&self) -> Result<(), Error<E>> {
+        if reference_matches(self.reference_resistor, self.curve.nominal_resistance()) {
+            Ok(())
+        } else {
+            Err(Error::ReferenceMismatch)
+        }
+    }
+
+    /// Set the calibration reference resistance. This can be used to calibrate
+    /// inaccuracies of both the reference resistor and the PT100 element.
+    ///
+    /// # Arguments
+    ///
+    /// * `calib` - A 32 bit integer specifying the reference resistance in ohms
+    ///             multiplied by 100, e.g. `40000` for 400 Ohms
+    ///
+    /// # Remarks
+    ///
+    /// You can perform calibration by putting the sensor in boiling (100
+    /// degrees Celsius) water and then measuring the raw value using
+    /// `read_raw`. Calculate `calib` as `(13851 << 15) / raw >> 1`.
+    ///
+    /// This overrides the value derived from `set_reference_resistor`, but
+    /// leaves the reported `reference_resistor` unchanged.
+    pub fn set_calibration(&mut self, calib: u32) {
+        self.calibration = calib;
+    }
+
+    /// Set the calibration like `set_calibration`, but reject values that
+    /// would cause arithmetic overflow when converting readings.
+    ///
+    /// # Arguments
+    ///
+    /// * `calib` - The reference resistance in ohms multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `Error::CalibrationOutOfRange` and leaves the calibration
+    /// unchanged if `calib` is `0` or larger than `MAX_CALIBRATION` (10 kOhms),
+    /// beyond which the largest readings are limited to `MAX_CALIBRATION`.
+    /// This catches mistyped calibration constants, which would otherwise
+    /// result in plausible looking but wrong readings.
+    pub fn try_set_calibration(&mut self, calib: u32) -> Result<(), Error<E>> {
+        if calib == 0 || calib > MAX_CALIBRATION {
+            return Err(Error::CalibrationOutOfRange);
+        }
+
+        self.calibration = calib;
+
+        Ok(())
+    }
+
+    /// Discard all calibration and return to the nominal behaviour.
+    ///
+    /// # Remarks
+    ///
+    /// This restores the calibration to the nominal reference resistance set
+    /// with `set_reference_resistor`, i.e. the reference resistance in ohms
+    /// multiplied by 100: `40000` for the default 400 Ohms reference (PT100),
+    /// `43000` for a 430 Ohms reference (PT100) or `430000` for a 4.3 kOhms
+    /// reference (PT1000). The offset set with `set_temperature_offset` or
+    /// `tare` is reset to `0` and the two-point calibration and calibrated
+    /// range are cleared. The lead resistance is left unchanged.
+    pub fn reset_calibration(&mut self) {
+        self.calibration = self.reference_resistor;
+        self.two_point = None;
+        self.calibrated_range = None;
+        self.offset = 0;
+    }
+
+    /// Set a two-point gain and offset correction of the measured resistance.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The measured and the actual resistance at the low
+    ///           calibration point, both in ohms multiplied by 100.
+    /// * `high` - The measured and the actual resistance at the high
+    ///            calibration point, both in ohms multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The measured resistances are those reported by `read_ohms` without a
+    /// two-point calibration, i.e. after the lead resistance is subtracted.
+    /// Measured values are mapped linearly through the two points, see the
+    /// crate documentation for the order of the corrections.
+    ///
+    /// Returns `Error::InvalidCalibration` if both measured resistances are
+    /// equal, as no gain can be determined from them, or if an actual
+    /// resistance is larger than `MAX_CALIBRATION`. Corrected resistances are
+    /// limited to `MAX_CALIBRATION` before they are converted to a temperature.
+    pub fn set_two_point_calibration(
+        &mut self,
+        low: (u32, u32),
+        high: (u32, u32),
+    ) -> Result<(), Error<E>> {
+        if !valid_two_point(low, high) {
+            return Err(Error::InvalidCalibration);
+        }
+
+        self.two_point = Some([low, high]);
+        Ok(())
+    }
+
+    /// Remove the two-point calibration set with `set_two_point_calibration`.
+    pub fn clear_two_point_calibration(&mut self) {
+        self.two_point = None;
+    }
+
+    /// Set the temperature range validated by the calibration.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The lowest calibrated temperature in degrees Celsius
+    ///           multiplied by 100.
+    /// * `high` - The highest calibrated temperature in degrees Celsius
+    ///            multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// This is typically the span of the two-point calibration, e.g. `0` and
+    /// `10000` when calibrating at 0 C° and 100 C°. Readings outside of it
+    /// rely on extrapolation and are flagged by `read_range_checked`. Until
+    /// set, the range covered by the lookup table of the RTD curve is used.
+    pub fn set_calibrated_range(&mut self, low: i32, high: i32) {
+        self.calibrated_range = Some((low.min(high), low.max(high)));
+    }
+
+    /// The temperature range validated by the calibration, see
+    /// `set_calibrated_range`.
+    pub fn calibrated_range(&self) -> (i32, i32) {
+        self.calibrated_range
+            .unwrap_or_else(|| self.curve.lookup_table().temperature_range())
+    }
+
+    /// Set the resistance of the sensor leads, which is subtracted from the
+    /// measured resistance before conversion.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohms_100` - The total resistance of the leads in series with the
+    ///                RTD in ohms multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// This is a correction in the resistance domain, applied by `read_ohms`
+    /// and all temperature readings. It is only applied for two wire sensors
+    /// (see `set_wire_count`), where the lead resistance adds to the RTD
+    /// resistance. It defaults to `0`. For a constant error in the temperature
+    /// domain use `set_temperature_offset` instead.
+    pub fn set_lead_resistance(&mut self, ohms_100: u32) {
+        self.lead_resistance = ohms_100;
+    }
+
+    /// Set how the reference resistor and the RTD are wired.
+    ///
+    /// # Arguments
+    ///
+    /// * `wiring` - The wiring of the reference resistor, defaults to
+    ///              `RefWiring::Standard`.
+    ///
+    /// # Remarks
+    ///
+    /// This changes how the ADC code is converted to a resistance in step 1
+    /// of the correction pipeline, and back for the fault thresholds. The
+    /// reference resistance is still set with `set_reference_resistor` and
+    /// `set_calibration`.
+    pub fn set_reference_wiring(&mut self, wiring: RefWiring) {
+        self.reference_wiring = wiring;
+    }
+
+    /// Set an offset that is added to the converted temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius_100` - The offset in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// This is a final correction in the temperature domain, applied by all
+    /// methods returning a temperature. It defaults to `0`. Use it for
+    /// constant temperature errors, e.g. from additional series elements or
+    /// as determined by `tare`. Resistance errors such as from the sensor
+    /// leads should be corrected with `set_lead_resistance` instead, as their
+    /// effect on the temperature varies with the slope of the RTD curve.
+    pub fn set_temperature_offset(&mut self, celsius_100: i32) {
+        self.offset = celsius_100;
+    }
+
+    /// Set an offset that is added to the converted temperature.
+    ///
+    /// # Remarks
+    ///
+    /// This is the same as `set_temperature_offset`, which makes the
+    /// distinction from the lead resistance correction explicit.
+    #[deprecated(note = "use `set_temperature_offset` instead")]
+    pub fn set_offset(&mut self, celsius_100: i32) {
+        self.set_temperature_offset(celsius_100);
+    }
+
+    /// Zero the sensor against a reference temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference_celsius_100` - The known current temperature of the
+    ///                             sensor in degrees Celsius multiplied by 100.
+    /// * `delay` - A delay provider used to wait for the next conversion.
+    ///
+    /// # Remarks
+    ///
+    /// This waits for the next conversion, reads the temperature and sets the
+    /// offset (see `set_temperature_offset`) such that the reading matches the
+    /// reference. The device must be configured to convert, otherwise
+    /// `Error::Timeout` is returned.
+    pub fn tare(
+        &mut self,
+        reference_celsius_100: i32,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<(), Error<E>> {
+        self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+        let temp = self.read_default_conversion()? - self.offset;
+        self.offset = reference_celsius_100 - temp;
+
+        Ok(())
+    }
+
+    /// Calibrate the sensor against a reference thermometer at any
+    /// temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference_c100` - The temperature measured by the reference in
+    ///                      degrees Celsius multiplied by 100.
+    /// * `delay` - A delay provider used to wait for the next conversion.
+    ///
+    /// # Remarks
+    ///
+    /// This waits for the next conversion, determines the resistance the RTD
+    /// should have at the reference temperature from the RTD curve, taking
+    /// the other corrections into account, and sets the calibration (see
+    /// `set_calibration`) such that the reading matches the reference.
+    ///
+    /// This is a single point gain correction: it assumes the error is
+    /// proportional to the resistance, as caused by a reference resistor
+    /// deviating from its nominal value. Constant errors are corrected with
+    /// `tare` instead. The further the reference temperature is from 0 C°,
+    /// the better the gain is determined.
+    ///
+    /// Returns `Error::ConversionFault` if the fault bit of the reading is set
+    /// and `Error::CalibrationOutOfRange` if the resulting calibration is
+    /// implausible, leaving the calibration unchanged in both cases.
+    pub fn calibrate_against_reference(
+        &mut self,
+        reference_c100: i32,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<(), Error<E>> {
+        self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+        let raw = self.read_raw()?;
+        if raw & 1 != 0 {
+            return Err(Error::ConversionFault);
+        }
+
+        let code = raw >> 1;
+        if code == 0 {
+            return Err(Error::CalibrationOutOfRange);
+        }
+
+        let ohms = self.temperature_to_measured_ohms(reference_c100);
+        let calib = self.calibration_for(code, ohms);
+
+        self.try_set_calibration(calib)
+    }
+
+    /// Check the reading against a known ambient temperature to detect drift
+    /// of the reference resistor.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_room_temp_c100` - The known temperature of the sensor in
+    ///                               degrees Celsius multiplied by 100, e.g.
+    ///                               from a trusted thermometer.
+    /// * `delay` - A delay provider used to wait for the next conversion.
+    ///
+    /// # Remarks
+    ///
+    /// Waits for the next conversion and returns the reading minus the
+    /// expected temperature in degrees Celsius multiplied by 100. A deviation
+    /// which grows over time suggests the reference resistor (or the RTD) has
+    /// drifted and the sensor should be recalibrated, e.g. with
+    /// `calibrate_against_reference`. Nothing is changed.
+    ///
+    /// *Note*: The result is only meaningful if the sensor really is at the
+    /// expected temperature. At room temperature a reference error of 0.1%
+    /// shows as a deviation of about 0.3 C°, so the known temperature must
+    /// be more accurate than that.
+    pub fn reference_health_check(
+        &mut self,
+        expected_room_temp_c100: i32,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<i32, Error<E>> {
+        self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+
+        Ok(self.read_default_conversion()? - expected_room_temp_c100)
+    }
+
+    /// Read the latest conversion and fold it into the running average.
+    ///
+    /// # Remarks
+    ///
+    /// This is meant to be called from the interrupt handler of the ready
+    /// pin, so that every completed conversion is accumulated without
+    /// polling, while the application queries `current_average` whenever it
+    /// needs a value. The driver must then be shared between the interrupt
+    /// handler and the application, e.g. in a critical section mutex.
+    ///
+    /// The average is an exponential moving average: every reading moves it
+    /// by `1 / 2^shift` of its difference to the average, see
+    /// `set_average_shift`. The first reading initializes the average.
+    pub fn accumulate(&mut self) -> Result<(), Error<E>> {
+        let temp = self.read_default_conversion()?;
+        self.average.process(pipeline::RawReading {
+            raw: 0,
+            value: temp,
+        });
+
+        Ok(())
+    }
+
+    /// The running average of the readings taken by `accumulate`.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100, or
+    /// `None` if nothing has been accumulated yet.
+    pub fn current_average(&self) -> Option<i32> {
+        self.average.value()
+    }
+
+    /// Set the coefficient of the running average of `accumulate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `shift` - Every reading moves the average by `1 / 2^shift` of its
+    ///             difference to the average, clamped to `0..=16`. The
+    ///             default is `DEFAULT_AVERAGE_SHIFT`, i.e. 1/8. In automatic
+    ///             conversion mode the average settles to within about 5% of
+    ///             a step change after `3 * 2^shift` conversions.
+    ///
+    /// # Remarks
+    ///
+    /// This restarts the average.
+    pub fn set_average_shift(&mut self, shift: u8) {
+        self.average = pipeline::Smoothing::new(shift);
+    }
+
+    /// Capture the current temperature as the baseline for `read_delta`.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the next conversion.
+    ///
+    /// # Remarks
+    ///
+    /// This waits for the next conversion and stores the temperature. The
+    /// baseline persists until it is marked again.
+    pub fn mark_baseline(&mut self, delay: &mut impl DelayUs<u32>) -> Result<(), Error<E>> {
+        self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+        self.baseline = Some(self.read_default_conversion()?);
+
+        Ok(())
+    }
+
+    /// Read the change in temperature since the baseline was marked.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the current temperature minus the baseline in
+    /// degrees Celsius multiplied by 100, i.e. positive if the temperature
+    /// has risen. Returns `Error::NoBaseline` if `mark_baseline` hasn't been
+    /// called.
+    pub fn read_delta(&mut self) -> Result<i32, Error<E>> {
+        let baseline = self.baseline.ok_or(Error::NoBaseline)?;
+
+        Ok(self.read_default_conversion()? - baseline)
+    }
+
+    /// Read the temperature, rejecting implausibly fast changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_delta_c100` - The largest accepted change from the previous
+    ///                      accepted reading in degrees Celsius multiplied by
+    ///                      100.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// Returns `Error::RateExceeded` if the reading differs from the previous
+    /// reading accepted by this method by more than `max_delta_c100`, which
+    /// usually indicates interference or a loose connection rather than a
+    /// real change. Rejected readings are not remembered, so a single spike
+    /// doesn't cause the next valid reading to be rejected. The first reading
+    /// can't be checked and is always accepted. After a genuine step change,
+    /// call `reset_rate_check` to accept the new temperature.
+    pub fn read_rate_checked(&mut self, max_delta_c100: i32) -> Result<i32, Error<E>> {
+        let temp = self.read_default_conversion()?;
+
+        if let Some(previous) = self.last_accepted {
+            if (temp - previous).abs() > max_delta_c100 {
+                return Err(Error::RateExceeded);
+            }
+        }
+        self.last_accepted = Some(temp);
+
+        Ok(temp)
+    }
+
+    /// Forget the previous reading of `read_rate_checked`, so that the next
+    /// reading is accepted unconditionally.
+    pub fn reset_rate_check(&mut self) {
+        self.last_accepted = None;
+    }
+
+    /// Serialize the calibration so that it can be persisted, e.g. to EEPROM
+    /// or flash.
+    ///
+    /// # Remarks
+    ///
+    /// The returned blob holds all corrections of the correction pipeline:
+    /// the calibration (see `set_calibration`), the lead resistance, the
+    /// temperature offset, the reference wiring, the two-point calibration
+    /// and the calibrated range. It consists of, with all integers little
+    /// endian:
+    ///
+    /// * `0` - The version, currently `2`.
+    /// * `1..5` - The calibration as `u32`.
+    /// * `5..9` - The lead resistance as `u32`.
+    /// * `9..13` - The temperature offset as `i32`.
+    /// * `13..29` - The two-point calibration as four `u32`, or zeros.
+    /// * `29..37` - The calibrated range as two `i32`, or zeros.
+    /// * `37` - Flags for swapped wiring (bit 0), a two-point calibration
+    ///          (bit 1) and a calibrated range (bit 2).
+    /// * `38` - A checksum of the preceding bytes.
+    ///
+    /// Use `import_calibration` to restore it.
+    pub fn export_calibration(&self) -> [u8; CALIBRATION_BLOB_LEN] {
+        let mut blob = [0u8; CALIBRATION_BLOB_LEN];
+        let mut flags = 0;
+        blob[0] = CALIBRATION_BLOB_VERSION;
+        blob[1..5].copy_from_slice(&self.calibration.to_le_bytes());
+        blob[5..9].copy_from_slice(&self.lead_resistance.to_le_bytes());
+        blob[9..13].copy_from_slice(&self.offset.to_le_bytes());
+
+        if self.reference_wiring == RefWiring::Swapped {
+            flags |= CALIBRATION_FLAG_SWAPPED;
+        }
+
+        if let Some([(m_lo, a_lo), (m_hi, a_hi)]) = self.two_point {
+            flags |= CALIBRATION_FLAG_TWO_POINT;
+            for (chunk, value) in blob[13..29].chunks_mut(4).zip([m_lo, a_lo, m_hi, a_hi]) {
+                chunk.copy_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        if let Some((low, high)) = self.calibrated_range {
+            flags |= CALIBRATION_FLAG_RANGE;
+            blob[29..33].copy_from_slice(&low.to_le_bytes());
+            blob[33..37].copy_from_slice(&high.to_le_bytes());
+        }
+
+        blob[37] = flags;
+        blob[38] = calibration_checksum(&blob[..38]);
+
+        blob
+    }
+
+    /// Restore a calibration previously serialized with `export_calibration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The blob as returned by `export_calibration`.
+    ///
+    /// # Remarks
+    ///
+    /// Restores all corrections stored by `export_calibration`. Returns
+    /// `Error::InvalidCalibration` if the blob has the wrong length, an
+    /// unknown version, a checksum mismatch, unknown flags or a two-point
+    /// calibration rejected by `set_two_point_calibration`, and
+    /// `Error::CalibrationOutOfRange` if the calibration is rejected by
+    /// `try_set_calibration`. The current calibration is left untouched in
+    /// either case.
+    pub fn import_calibration(&mut self, bytes: &[u8]) -> Result<(), Error<E>> {
+        if bytes.len() != CALIBRATION_BLOB_LEN
+            || bytes[0] != CALIBRATION_BLOB_VERSION
+            || bytes[38] != calibration_checksum(&bytes[..38])
+            || bytes[37] & !CALIBRATION_FLAGS != 0
+        {
+            return Err(Error::InvalidCalibration);
+        }
+
+        let flags = bytes[37];
+        let calibration = le_u32(&bytes[1..5]);
+        if calibration == 0 || calibration > MAX_CALIBRATION {
+            return Err(Error::CalibrationOutOfRange);
+        }
+
+        let two_point = if flags & CALIBRATION_FLAG_TWO_POINT != 0 {
+            let low = (le_u32(&bytes[13..17]), le_u32(&bytes[17..21]));
+            let high = (le_u32(&bytes[21..25]), le_u32(&bytes[25..29]));
+            if !valid_two_point(low, high) {
+                return Err(Error::InvalidCalibration);
+            }
+            Some([low, high])
+        } else {
+            None
+        };
+
+        let calibrated_range = if flags & CALIBRATION_FLAG_RANGE != 0 {
+            let low = le_u32(&bytes[29..33]) as i32;
+            let high = le_u32(&bytes[33..37]) as i32;
+            Some((low.min(high), low.max(high)))
+        } else {
+            None
+        };
+
+        self.calibration = calibration;
+        self.lead_resistance = le_u32(&bytes[5..9]);
+        self.offset = le_u32(&bytes[9..13]) as i32;
+        self.reference_wiring = if flags & CALIBRATION_FLAG_SWAPPED != 0 {
+            RefWiring::Swapped
+        } else {
+            RefWiring::Standard
+        };
+        self.two_point = two_point;
+        self.calibrated_range = calibrated_range;
+
+        Ok(())
+    }
+
+    /// Read the raw resistance value.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the resistance in Ohms multiplied by 100, see
+    /// `read_milliohms` for a finer scale and `read_ohms_raw` for a plain
+    /// `u32`.
+    ///
+    /// The MAX31865 measures ratiometrically: the 15 bit ADC code is the ratio
+    /// `R_RTD / R_REF` scaled to 2^15, so the resistance is the code times the
+    /// calibrated reference resistance divided by 2^15. For a board with the
+    /// reference resistor and RTD swapped see `set_reference_wiring`. The
+    /// resistance corrections of steps 2 and 3 of the correction pipeline are
+    /// applied.
+    pub fn read_ohms(&mut self) -> Result<Ohms, Error<E>> {
+        self.read_ohms_raw().map(Ohms)
+    }
+
+    /// Read the resistance value in Ohms multiplied by 100 as a plain `u32`.
+    ///
+    /// # Remarks
+    ///
+    /// This is the same as `read_ohms`, without the `Ohms` wrapper.
+    pub fn read_ohms_raw(&mut self) -> Result<u32, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok(self.correct_resistance(raw))
+    }
+
+    /// Read the resistance value in Ohms as a floating point value.
+    ///
+    /// # Remarks
+    ///
+    /// This is computed from `read_milliohms`, so it has a resolution of
+    /// 1 mOhm. An `f32` holds about 7 significant digits, which represents
+    /// this resolution exactly for resistances of up to about 16 kOhm.
+    ///
+    /// *Note*: This requires either the `std` or the `libm` feature.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn read_ohms_f32(&mut self) -> Result<f32, Error<E>> {
+        Ok(self.read_milliohms()? as f32 / 1000.0)
+    }
+
+    /// Read the resistance value in milliohms.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in milliohms, i.e. Ohms multiplied by
+    /// 1000, as opposed to `read_ohms` which returns Ohms multiplied by 100.
+    /// The same resistance corrections are applied, but the extra decimal of
+    /// the ADC code is kept. E.g. 138.506 Ohms is read as `138506` here and
+    /// as `13850` by `read_ohms`.
+    pub fn read_milliohms(&mut self) -> Result<u32, Error<E>> {
+        let raw = self.read_raw()?;
+        let milliohms = self.code_to_resistance(raw >> 1, 1000);
+
+        Ok(self.correct_scaled_resistance(milliohms, 1000))
+    }
+
+    /// Read the raw resistance value and then perform conversion to degrees Celsius.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// The lookup table is selected by the RTD curve type parameter of the
+    /// driver, see `new_with_curve`.
+    ///
+    /// *Note*: In debug builds this panics if V_BIAS has not been enabled
+    /// through the driver, e.g. with `configure`, as the device doesn't
+    /// convert without it. The same check applies to all methods converting
+    /// a reading to a temperature and is compiled out in release builds.
+    pub fn read_default_conversion(&mut self) -> Result<i32, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok(self.apply_corrections(raw, self.curve.lookup_table()))
+    }
+
+    /// Read the temperature as a `Celsius` value.
+    ///
+    /// # Remarks
+    ///
+    /// This is the same as `read_default_conversion`, but makes the unit and
+    /// scale of the reading explicit in its type.
+    pub fn read_celsius(&mut self) -> Result<Celsius, Error<E>> {
+        self.read_default_conversion().map(Celsius)
+    }
+
+    /// Read the latest conversion in automatic conversion mode.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// This is the call to use when the device is configured for automatic
+    /// (continuous) conversion mode: it only reads and converts the RTD
+    /// registers, which hold the result of the last completed conversion,
+    /// without triggering a conversion or changing the configuration. Reading
+    /// the registers releases the ready pin until the next conversion
+    /// completes. In normally off mode use a one-shot read such as
+    /// `read_one_shot_low_power` instead.
+    ///
+    /// *Note*: In debug builds this panics if automatic conversion mode has
+    /// not been enabled through the driver.
+    pub fn read_latest(&mut self) -> Result<i32, Error<E>> {
+        debug_assert!(
+            self.config & CONFIG_CONVERSION_MODE != 0,
+            "MAX31865: read_latest() requires automatic conversion mode"
+        );
+
+        self.read_default_conversion()
+    }
+
+    /// Read only the RTD MSB register and convert it to degrees Celsius.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// See `read_raw_coarse` for the resolution of this reading. The missing
+    /// low bits are assumed to be in the middle of their range, so the error
+    /// is at most half a coarse step in either direction.
+    pub fn read_coarse_conversion(&mut self) -> Result<i32, Error<E>> {
+        let msb = self.read_raw_coarse()? as u16;
+
+        Ok(self.apply_corrections((msb << 8) | 0x80, self.curve.lookup_table()))
+    }
+
+    /// Set the fault thresholds of the device as raw ADC codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The 15 bit ADC code below which the low threshold fault is
+    ///           raised.
+    /// * `high` - The 15 bit ADC code at or above which the high threshold
+    ///            fault is raised.
+    ///
+    /// # Remarks
+    ///
+    /// The device compares every conversion against the thresholds in
+    /// hardware and latches the threshold faults in the fault status
+    /// register. Codes above `0x7FFF` are saturated.
+    pub fn set_thresholds_raw(&mut self, low: u16, high: u16) -> Result<(), Error<E>> {
+        let [high_msb, high_lsb, low_msb, low_lsb] = threshold_registers(low, high);
+
+        self.write(Register::HIGH_FAULT_THRESHOLD_MSB, high_msb)?;
+        self.write(Register::HIGH_FAULT_THRESHOLD_LSB, high_lsb)?;
+        self.write(Register::LOW_FAULT_THRESHOLD_MSB, low_msb)?;
+        self.write(Register::LOW_FAULT_THRESHOLD_LSB, low_lsb)
+    }
+
+    /// Set the fault thresholds of the device as temperatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The low threshold in degrees Celsius multiplied by 100.
+    /// * `high` - The high threshold in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The temperatures are converted to ADC codes by inverting the
+    /// correction pipeline, so the hardware trips at the same temperatures
+    /// as reported by `read_default_conversion`. The corrections must
+    /// therefore be set up before calling this. With `RefWiring::Swapped` the
+    /// code falls as the temperature rises, so the thresholds are swapped.
+    pub fn set_thresholds(&mut self, low: i32, high: i32) -> Result<(), Error<E>> {
+        let (low, high) = self.threshold_codes(low, high);
+
+        self.set_thresholds_raw(low, high)
+    }
+
+    /// Configure the device and set the fault thresholds in one go.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration to write to the device, see
+    ///              `apply_config`.
+    /// * `low` - The low threshold in degrees Celsius multiplied by 100.
+    /// * `high` - The high threshold in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// This is equivalent to `apply_config` followed by `set_thresholds`, but
+    /// writes the four threshold registers in a single burst, so that only
+    /// two SPI transactions are needed rather than five. As for
+    /// `set_thresholds`, the corrections must be set up before calling this.
+    pub fn init(&mut self, config: config::Config, low: i32, high: i32) -> Result<(), Error<E>> {
+        self.apply_config(config)?;
+
+        let (low, high) = self.threshold_codes(low, high);
+        self.write_burst(
+            Register::HIGH_FAULT_THRESHOLD_MSB,
+            &threshold_registers(low, high),
+        )
+    }
+
+    /// The low and high threshold codes for the given temperatures.
+    fn threshold_codes(&self, low: i32, high: i32) -> (u16, u16) {
+        let low = self.temperature_to_code(low);
+        let high = self.temperature_to_code(high);
+
+        match self.reference_wiring {
+            RefWiring::Standard => (low, high),
+            RefWiring::Swapped => (high, low),
+        }
+    }
+
+    /// Update the fault thresholds and read the temperature, e.g. for alarm
+    /// limits that track a setpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `low_c` - The low threshold in degrees Celsius multiplied by 100.
+    /// * `high_c` - The high threshold in degrees Celsius multiplied by 100.
+    /// * `delay` - A delay provider used to wait between polls of the ready
+    ///             pin.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// The thresholds are written first, see `set_thresholds`, then the next
+    /// conversion is awaited and read, so that any threshold fault it raises
+    /// is checked against the new thresholds. Each call costs four register
+    /// writes and two register reads on the SPI bus.
+    pub fn read_and_update_thresholds(
+        &mut self,
+        low_c: i32,
+        high_c: i32,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<i32, Error<E>> {
+        self.set_thresholds(low_c, high_c)?;
+        self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+
+        self.read_default_conversion()
+    }
+
+    /// Read the temperature and the threshold faults raised by the device.
+    ///
+    /// # Remarks
+    ///
+    /// The thresholds are compared in hardware, see `set_thresholds`, so
+    /// this reports the exact trip points of the device without comparing in
+    /// software. The threshold faults remain latched until cleared, and are
+    /// only updated by the device as conversions complete.
+    pub fn read_and_check_thresholds(&mut self) -> Result<ThresholdResult, Error<E>> {
+        let (temperature, status) = self.read_checked()?;
+        let (high_tripped, low_tripped) = match self.reference_wiring {
+            RefWiring::Standard => (status.high_threshold(), status.low_threshold()),
+            RefWiring::Swapped => (status.low_threshold(), status.high_threshold()),
+        };
+
+        Ok(ThresholdResult {
+            temperature,
+            high_tripped,
+            low_tripped,
+        })
+    }
+
+    /// Read the temperature and check that it lies within the calibrated
+    /// range.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100,
+    /// together with `true` if it lies within the range returned by
+    /// `calibrated_range` (inclusive). Readings outside of the range are
+    /// still returned, but are less trustworthy.
+    pub fn read_range_checked(&mut self) -> Result<(i32, bool), Error<E>> {
+        let temp = self.read_default_conversion()?;
+        let (low, high) = self.calibrated_range();
+
+        Ok((temp, (low..=high).contains(&temp)))
+    }
+
+    /// Read the temperature, rejecting readings beyond the lookup table of the
+    /// RTD curve.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// The other reads extrapolate resistances outside of the lookup table,
+    /// e.g. the full scale reading of an open RTD converts to a temperature
+    /// far above 850 C°. This returns `Error::OutOfSensorRange` instead if the
+    /// corrected resistance lies outside of the table, i.e. the temperature
+    /// is beyond the physical range of the sensor (-200 C° to 860 C° for the
+    /// built-in tables), see `LookupTable::lookup_temperature_checked`.
+    pub fn read_sensor_range_checked(&mut self) -> Result<i32, Error<E>> {
+        let raw = self.read_raw()?;
+        let ohms = self.correct_resistance(raw).min(i32::MAX as u32) as i32;
+
+        self.curve
+            .lookup_table()
+            .lookup_temperature_checked(ohms)
+            .map(|temp| temp + self.offset)
+            .ok_or(Error::OutOfSensorRange)
+    }
+
+    /// Read the RTD registers and run the value through a pipeline of
+    /// processors.
+    ///
+    /// # Arguments
+    ///
+    /// * `pipeline` - The processors to apply, in order.
+    ///
+    /// # Remarks
+    ///
+    /// The raw value as returned by `read_raw` is passed to every stage, and
+    /// the value produced by the last stage is returned. None of the
+    /// corrections configured on the driver are applied; use the processors
+    /// in the `pipeline` module, such as `CodeToOhms` and `CurveConversion`,
+    /// to compose them as required, see `pipeline::Pipeline`.
+    pub fn read_processed<const N: usize>(
+        &mut self,
+        pipeline: &mut pipeline::Pipeline<N>,
+    ) -> Result<i32, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok(pipeline.process(raw))
+    }
+
+    /// Read the temperature together with the fault status register.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100,
+    /// together with the contents of the fault status register read right
+    /// after the RTD registers.
+    ///
+    /// Unlike `read_default_conversion_checked` this doesn't fail on fault
+    /// bits, so that soft faults such as a crossed threshold can be logged
+    /// alongside every sample. The faults remain latched until cleared.
+    pub fn read_checked(&mut self) -> Result<(i32, FaultStatus), Error<E>> {
+        let temp = self.read_default_conversion()?;
+        let status = self.read_fault_status()?;
+
+        Ok((temp, status))
+    }
+
+    /// Read the temperature like `read_default_conversion`, but check the
+    /// fault bit of the reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the next conversion
+    ///             before re-reading.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// In automatic conversion mode the fault bit may briefly be set by a
+    /// transient. If the fault bit is set, the registers are re-read after
+    /// waiting for the next conversion, up to the number of times set with
+    /// `set_fault_retries` (default `1`). If the fault bit is still set after
+    /// that, or in normally off mode where there is no next conversion,
+    /// `Error::ConversionFault` is returned.
+    pub fn read_default_conversion_checked(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<i32, Error<E>> {
+        let mut retries = if self.config & CONFIG_CONVERSION_MODE != 0 {
+            self.fault_retries
+        } else {
+            0
+        };
+
+        loop {
+            let raw = self.read_raw()?;
+            if raw & 1 == 0 {
+                return Ok(self.apply_corrections(raw, self.curve.lookup_table()));
+            }
+
+            if retries == 0 {
+                return Err(Error::ConversionFault);
+            }
+
+            retries -= 1;
+            delay.delay_us(CONVERSION_SETTLE_US);
+        }
+    }
+
+    /// Read the temperature, retrying on transient faults.
+    ///
+    /// # Arguments
+    ///
+    /// * `retries` - The maximum number of retries after the first attempt.
+    /// * `delay` - A delay provider used to wait between attempts.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// An attempt fails with `Error::ConversionFault` if the fault bit of the
+    /// reading is set, or with `Error::SPIError` if the bus transfer fails.
+    /// On either, the next attempt is made after waiting for the next
+    /// conversion. The first good reading or the last error is returned,
+    /// other errors are returned immediately.
+    ///
+    /// *Note*: Hard faults such as an open or shorted sensor persist and still
+    /// fail once the retries are exhausted. The fault status register can be
+    /// read to determine the cause.
+    pub fn read_with_retries(
+        &mut self,
+        retries: u8,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<i32, Error<E>> {
+        let mut attempt = 0;
+
+        loop {
+            let result = self.read_raw().and_then(|raw| {
+                if raw & 1 == 0 {
+                    Ok(self.apply_corrections(raw, self.curve.lookup_table()))
+                } else {
+                    Err(Error::ConversionFault)
+                }
+            });
+
+            match result {
+                Err(Error::ConversionFault) | Err(Error::SPIError(_)) if attempt < retries => {
+                    attempt += 1;
+                    delay.delay_us(CONVERSION_SETTLE_US);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Read the temperature, substituting a default on sensor faults.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_fault` - The temperature in degrees Celsius multiplied by 100
+    ///                returned instead of a faulted reading, e.g. a high
+    ///                temperature that makes a control loop turn on cooling.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// Sensor faults, such as an open or shorted RTD, are reported by the
+    /// device through the fault bit of the reading. These are substituted
+    /// with `on_fault`, as the bus works and the device just has no valid
+    /// temperature. An open RTD reads as `0xFFFF`, the full scale code with
+    /// the fault bit set. Communication faults, i.e. errors of the SPI bus or
+    /// the pins, are still returned as errors, as they mean the state of the
+    /// sensor is unknown and shouldn't be masked.
+    ///
+    /// *Note*: A floating MISO line also reads as `0xFFFF` and can't be told
+    /// apart from an open RTD by the reading alone, so it is substituted as
+    /// well. Use `check_bus_speed` or `identify` to verify the bus.
+    pub fn read_with_default(&mut self, on_fault: i32) -> Result<i32, Error<E>> {
+        match self.read_raw()? {
+            raw if raw & 1 != 0 => Ok(on_fault),
+            raw => Ok(self.apply_corrections(raw, self.curve.lookup_table())),
+        }
+    }
+
+    /// Set how often `read_default_conversion_checked` re-reads a reading
+    /// with the fault bit set in automatic conversion mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `retries` - The number of re-reads, `0` to report every fault.
+    pub fn set_fault_retries(&mut self, retries: u8) {
+        self.fault_retries = retries;
+    }
+
+    /// The number of conversions, faults and SPI errors since the driver was
+    /// created or `reset_stats` was called.
+    ///
+    /// # Remarks
+    ///
+    /// The conversions are counted whenever the RTD registers are read, e.g.
+    /// by `read_raw`, `read_raw_bytes` or `finish_read`, and the faults when
+    /// the fault bit of such a reading is set. Failed SPI transfers are
+    /// counted for every register access. The counters saturate at
+    /// `u32::MAX`.
+    ///
+    /// *Note*: The counters are only kept in RAM by the driver and restart
+    /// from zero after a power cycle or reset of the microcontroller.
+    pub fn stats(&self) -> DeviceStats {
+        self.stats
+    }
+
+    /// Reset the counters returned by `stats` to zero.
+    pub fn reset_stats(&mut self) {
+        self.stats = DeviceStats::default();
+    }
+
+    fn count_conversion(&mut self, raw: u16) -> u16 {
+        self.stats.conversions = self.stats.conversions.saturating_add(1);
+        if raw & 1 != 0 {
+            self.stats.faults = self.stats.faults.saturating_add(1);
+        }
+
+        raw
+    }
+
+    fn spi_error(&mut self, e: E) -> Error<E> {
+        self.stats.spi_errors = self.stats.spi_errors.saturating_add(1);
+
+        Error::SPIError(e)
+    }
+
+    /// Set how often the configuration register is checked for V_BIAS before
+    /// reading the RTD registers.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Check on every `interval`th read, `0` (the default) to
+    ///                never check.
+    ///
+    /// # Remarks
+    ///
+    /// If the device resets, e.g. after a brown-out, the configuration
+    /// register returns to `0x00` which disables V_BIAS and the readings are
+    /// meaningless. With the check enabled, the read methods return
+    /// `Error::BiasDisabled` instead when V_BIAS is found to be disabled. Each
+    /// check costs one additional register read.
+    pub fn set_bias_check_interval(&mut self, interval: u8) {
+        self.bias_check_interval = interval;
+        self.reads_since_bias_check = 0;
+    }
+
+    /// Read the raw resistance value and then perform conversion to degrees
+    /// Celsius, interpreting the reading as coming from the given RTD type.
+    ///
+    /// # Arguments
+    ///
+    /// * `rtd` - The RTD type whose lookup table should be used for the
+    ///           conversion.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    /// The temperature offset is applied as for `read_default_conversion`.
+    ///
+    /// The RTD registers are only read once, so this can be used to compare
+    /// how a single reading would be interpreted for different sensor types,
+    /// e.g. to identify which sensor is connected.
+    pub fn read_default_conversion_as(&mut self, rtd: Rtd) -> Result<i32, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok(self.apply_corrections(raw, rtd.lookup_table()))
+    }
+
+    /// Read the temperature together with the tolerance of the RTD.
+    ///
+    /// # Arguments
+    ///
+    /// * `class` - The tolerance class of the RTD element.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the temperature in degrees Celsius multiplied by 100 and the
+    /// ± tolerance of the RTD at that temperature in hundredths of a degree,
+    /// see `ToleranceClass`. This only covers the RTD element itself, not the
+    /// reference resistor or the ADC, see `read_with_uncertainty`.
+    pub fn read_with_tolerance(&mut self, class: ToleranceClass) -> Result<(i32, u16), Error<E>> {
+        let temp = self.read_default_conversion()?;
+
+        Ok((temp, class.band(temp)))
+    }
+
+    /// Read the temperature together with an estimate of its uncertainty.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the temperature in degrees Celsius multiplied by 100 and the
+    /// ± uncertainty in hundredths of a degree. The uncertainty accounts for
+    /// the noise of the ADC, assumed to be `ADC_NOISE_LSB` codes, converted to
+    /// a temperature using the local slope of the RTD curve. It does not
+    /// include the tolerance of the reference resistor or the RTD itself.
+    pub fn read_with_uncertainty(&mut self) -> Result<(i32, u16), Error<E>> {
+        let raw = self.read_raw()?;
+        let ohms = self.correct_resistance(raw) as i32;
+        let code = raw >> 1;
+        let low = self.code_to_resistance(code.saturating_sub(ADC_NOISE_LSB as u16), 100);
+        let high = self.code_to_resistance((code + ADC_NOISE_LSB as u16).min(0x7FFF), 100);
+        let noise = low.abs_diff(high).div_ceil(2) as i32;
+        let uncertainty = self
+            .curve
+            .lookup_table()
+            .temperature_uncertainty(ohms, noise);
+
+        Ok((
+            self.apply_corrections(raw, self.curve.lookup_table()),
+            uncertainty as u16,
+        ))
+    }
+
+    /// Guess the type of the connected RTD from its resistance.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the next conversion.
+    ///
+    /// # Remarks
+    ///
+    /// This waits for the next conversion and checks whether the measured
+    /// resistance is close to the nominal resistance of a PT100 or a PT1000,
+    /// i.e. within 0.8 to 1.6 times the nominal resistance (roughly -50 C° to
+    /// 150 C°). Returns `None` if it matches neither.
+    ///
+    /// *Note*: This assumes the sensor is near room temperature and that the
+    /// reference resistor has been set correctly with `set_reference_resistor`.
+    /// A PT1000 can't be detected with a reference resistor meant for a PT100,
+    /// as its resistance exceeds the reference.
+    pub fn detect_rtd_type(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<Option<Rtd>, Error<E>> {
+        self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+        let ohms = self.read_ohms_raw()?;
+
+        let detected = [Rtd::Pt100, Rtd::Pt1000].iter().copied().find(|rtd| {
+            let nominal = rtd.nominal_resistance();
+            ohms * 10 >= nominal * 8 && ohms * 10 <= nominal * 16
+        });
+
+        Ok(detected)
+    }
+
+    /// Read the temperature, reusing the previous reading if it is recent
+    /// enough.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_age` - The maximum age in ticks of a cached reading.
+    /// * `clock` - The tick source used to timestamp readings.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// If the last reading taken through this method is at most `max_age`
+    /// ticks old it is returned without any SPI traffic, otherwise the
+    /// temperature is read with `read_default_conversion` and cached.
+    pub fn cached_reading(
+        &mut self,
+        max_age: u32,
+        clock: &mut impl Clock,
+    ) -> Result<i32, Error<E>> {
+        let now = clock.now();
+
+        if let Some((temp, tick)) = self.cache {
+            if now.wrapping_sub(tick) <= max_age {
+                return Ok(temp);
+            }
+        }
+
+        let temp = self.read_default_conversion()?;
+        self.cache = Some((temp, now));
+
+        Ok(temp)
+    }
+
+    /// Sample the ready pin and timestamp the completion of conversions.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The tick source used to timestamp the ready pin.
+    ///
+    /// # Remarks
+    ///
+    /// Returns whether a new conversion is available. When the ready pin is
+    /// seen to be asserted after having been released, the current tick is
+    /// recorded as the completion time of the conversion, see
+    /// `read_with_age`. The timestamp can only be as precise as the ready pin
+    /// is sampled, so this should be called frequently, e.g. from the
+    /// application's main loop.
+    pub fn track_ready(&mut self, clock: &mut impl Clock) -> Result<bool, Error<E>> {
+        let ready = self.is_ready()?;
+
+        if ready && !self.ready_level {
+            self.ready_tick = Some(clock.now());
+        }
+        self.ready_level = ready;
+
+        Ok(ready)
+    }
+
+    /// Read the temperature along with the time since it was converted.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The tick source used to timestamp the ready pin.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the temperature in degrees Celsius multiplied by 100 and the
+    /// number of ticks of `clock` elapsed since the ready pin was last seen
+    /// to be asserted, see `track_ready`. The ticks are in whatever unit
+    /// `clock` counts in. If the ready pin has never been seen asserted the
+    /// age is `0`.
+    ///
+    /// In continuous conversion mode the conversion rate is fixed by the
+    /// filter, so the age should stay below the conversion time if the
+    /// readings keep up with the device.
+    pub fn read_with_age(&mut self, clock: &mut impl Clock) -> Result<(i32, u32), Error<E>> {
+        self.track_ready(clock)?;
+        let temp = self.read_default_conversion()?;
+        let now = clock.now();
+
+        // Reading the RTD registers releases the ready pin.
+        self.ready_level = false;
+
+        let age = self.ready_tick.map_or(0, |tick| now.wrapping_sub(tick));
+
+        Ok((temp, age))
+    }
+
+    /// Read the temperature into a buffer in a compact binary format.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The buffer to write the reading to, at least
+    ///           `READING_LEN` bytes long.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the number of bytes written, which is always `READING_LEN`.
+    /// The format is:
+    ///
+    /// - Bytes 0-1: The temperature in degrees Celsius multiplied by 10
+    ///   (tenths), as a big endian signed 16 bit integer, rounded to the
+    ///   nearest tenth.
+    /// - Byte 2: Status, bit 0 is set if the fault bit of the reading was set.
+    ///   The other bits are reserved and always `0`.
+    ///
+    /// Returns `Error::BufferTooSmall` without reading if `buf` is too short.
+    pub fn read_into(&mut self, buf: &mut [u8]) -> Result<usize, Error<E>> {
+        if buf.len() < READING_LEN {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let raw = self.read_raw()?;
+        let temp = self.apply_corrections(raw, self.curve.lookup_table());
+        let tenths = if temp >= 0 {
+            (temp + 5) / 10
+        } else {
+            (temp - 5) / 10
+        };
+
+        buf[..2].copy_from_slice(&(tenths as i16).to_be_bytes());
+        buf[2] = (raw & 1) as u8;
+
+        Ok(READING_LEN)
+    }
+
+    /// Read the temperature in the given unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The unit the temperature should be reported in.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the temperature in the given unit multiplied by
+    /// 100, e.g. 21200 for 212.00 °F. The scaling is the same for all units.
+    pub fn read_temperature_in(&mut self, unit: TempUnit) -> Result<i32, Error<E>> {
+        let temp = self.read_default_conversion()?;
+
+        Ok(unit.from_celsius(temp))
+    }
+
+    /// Read the temperature in degrees Celsius as a floating point value.
+    ///
+    /// # Remarks
+    ///
+    /// Rather than using the lookup table, this solves the Callendar–Van Dusen
+    /// equation for the measured resistance, see
+    /// `temp_conversion::cvd_temperature`. The offset set with
+    /// `set_temperature_offset` is applied.
+    ///
+    /// *Note*: This requires either the `std` or the `libm` feature, the latter
+    /// for `no_std` targets.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn read_temperature_f32(&mut self) -> Result<f32, Error<E>> {
+        let ohms = self.read_ohms()?.to_f32();
+        let r0 = self.curve.nominal_resistance() as f32 / 100.0;
+        let temp = temp_conversion::cvd_temperature(ohms, r0);
+
+        Ok(temp + self.offset as f32 / 100.0)
+    }
+
+    /// Read the temperature in millidegrees Celsius.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 1000.
+    ///
+    /// The resistance is computed in milliohms and interpolated at millidegree
+    /// scale in 64 bit arithmetic, so the full resolution of the 15 bit code
+    /// (about 3 millidegrees per code for a PT100 with a 400 Ohm reference) is
+    /// kept rather than appending a zero to `read_default_conversion`. This
+    /// improves the resolution, e.g. for differential and trend
+    /// measurements, but not the absolute accuracy. The corrections are
+    /// applied as for `read_default_conversion`.
+    pub fn read_temperature_millidegrees(&mut self) -> Result<i32, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok(self.apply_corrections_milli(raw))
+    }
+
+    /// Read the temperature with the given rounding.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How the temperature is rounded to hundredths of a degree,
+    ///            `RoundingMode::default()` rounds to the nearest value.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// The temperature is computed at millidegree precision as for
+    /// `read_temperature_millidegrees` and then rounded, whereas
+    /// `read_default_conversion` truncates during interpolation. See
+    /// `RoundingMode` for the behavior of each mode for negative values.
+    pub fn read_default_conversion_rounded(&mut self, mode: RoundingMode) -> Result<i32, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok(mode.divide(self.apply_corrections_milli(raw), 10))
+    }
+
+    /// Read the temperature in degrees Celsius as a fixed point value.
+    ///
+    /// # Remarks
+    ///
+    /// The temperature is computed as for `read_temperature_millidegrees` and
+    /// converted to the
+    /// 16 fractional bits directly, so no precision is lost by going through
+    /// the hundredths of a degree returned by `read_default_conversion`. The
+    /// corrections are applied as for `read_default_conversion`.
+    ///
+    /// *Note*: This requires the `fixed` feature.
+    #[cfg(feature = "fixed")]
+    pub fn read_temperature_fixed(&mut self) -> Result<fixed::types::I16F16, Error<E>> {
+        let raw = self.read_raw()?;
+        let millidegrees = self.apply_corrections_milli(raw) as i64;
+
+        Ok(fixed::types::I16F16::from_bits(
+            ((millidegrees << 16) / 1000) as i32,
+        ))
+    }
+
+    /// Read the temperature and split it into integer and fractional parts.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the value in degrees Celsius multiplied by 100, the whole
+    /// degrees, truncated towards zero, and the hundredths of a degree as a
+    /// positive value from `0` to `99`. E.g. -5.07 C° is returned as
+    /// `(-507, -5, 7)`.
+    ///
+    /// *Note*: Between 0 C° and -1 C° the whole degrees are `0`, so the sign
+    /// must be taken from the first value, e.g. -0.5 C° is `(-50, 0, 50)`.
+    pub fn read_temperature_full(&mut self) -> Result<(i32, i32, u8), Error<E>> {
+        let temp = self.read_default_conversion()?;
+
+        Ok((temp, temp / 100, (temp % 100).unsigned_abs() as u8))
+    }
+
+    /// Read the temperature as packed BCD for display drivers.
+    ///
+    /// # Remarks
+    ///
+    /// The temperature in degrees Celsius is encoded with a sign nibble, five
+    /// integer digits and two fractional digits, see
+    /// `temp_conversion::hundredths_to_bcd` for the exact layout. E.g.
+    /// -12.34 C° is returned as `0xF000_1234`.
+    pub fn read_temperature_bcd(&mut self) -> Result<u32, Error<E>> {
+        let temp = self.read_default_conversion()?;
+
+        Ok(temp_conversion::hundredths_to_bcd(temp))
+    }
+
+    /// Read the temperature and format it for display.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The unit the temperature should be reported in.
+    ///
+    /// # Remarks
+    ///
+    /// The temperature is formatted with two decimals and the unit symbol,
+    /// e.g. `-5.07°C` or `212.00°F`.
+    ///
+    /// *Note*: This requires the `heapless` feature.
+    #[cfg(feature = "heapless")]
+    pub fn format_temperature(&mut self, unit: TempUnit) -> Result<heapless::String<16>, Error<E>> {
+        use core::fmt::Write;
+
+        let temp = self.read_temperature_in(unit)?;
+        let sign = if temp < 0 { "-" } else { "" };
+        let abs = temp.unsigned_abs();
+
+        let mut formatted = heapless::String::new();
+        write!(
+            formatted,
+            "{}{}.{:02}{}",
+            sign,
+            abs / 100,
+            abs % 100,
+            unit.suffix()
+        )
+        .map_err(|_| Error::BufferTooSmall)?;
+
+        Ok(formatted)
+    }
+
+    /// Read the raw RTD value.
+    ///
+    /// # Remarks
+    ///
+    /// The raw value is the value of the combined MSB and LSB registers.
+    /// The first 15 bits specify the ohmic value in relation to the reference
+    /// resistor (i.e. 2^15 - 1 would be the exact same resistance as the reference
+    /// resistor). See manual for further information.
+    /// The last bit specifies if the conversion was successful.
+    pub fn read_raw(&mut self) -> Result<u16, Error<E>> {
+        self.check_bias()?;
+
+        let msb: u16 = self.read(Register::RTD_MSB)? as u16;
+        let lsb: u16 = self.read(Register::RTD_LSB)? as u16;
+
+        Ok(self.count_conversion((msb << 8) | lsb))
+    }
+
+    /// Read the RTD registers as the raw bytes received.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the RTD MSB and LSB registers exactly as received, read in a
+    /// single burst. Unlike the conversions, this keeps the fault bit and the
+    /// exact bit pattern, e.g. for forwarding readings with a checksum and
+    /// processing them later. `read_raw` returns the same value combined into
+    /// a `u16`.
+    pub fn read_raw_bytes(&mut self) -> Result<[u8; 2], Error<E>> {
+        let mut bytes = [0u8; 2];
+        self.read_burst(Register::RTD_MSB, 2, &mut bytes)?;
+        self.count_conversion(u16::from_be_bytes(bytes));
+
+        Ok(bytes)
+    }
+
+    /// Read only the RTD MSB register.
+    ///
+    /// # Remarks
+    ///
+    /// This is the upper 8 bits of the 15 bit ADC code and needs a single
+    /// register read instead of two, halving the SPI traffic.
+    ///
+    /// *Note*: One step of the coarse value is 1/256 of the reference
+    /// resistance, i.e. about 1.56 Ohm with a 400 Ohm reference. For a PT100
+    /// this is roughly 4 C°, so it is only suitable for crude monitoring such
+    /// as an over temperature trip. The fault bit is part of the LSB and is
+    /// not available, and the ready pin is only released by reading the LSB.
+    pub fn read_raw_coarse(&mut self) -> Result<u8, Error<E>> {
+        self.read(Register::RTD_MSB)
+    }
+
+    /// Read the 15 bit ADC code as a sign extended value.
+    ///
+    /// # Remarks
+    ///
+    /// A negative value means the most significant bit of the code is set,
+    /// i.e. the RTD resistance is above half of the reference resistance. With
+    /// the usual reference of four times the nominal RTD resistance this is
+    /// the case above roughly 266 C°, well within the range of the sensor.
+    ///
+    /// *Note*: A negative value only points to a wiring fault, such as an open
+    /// RTD pulling the input towards REFIN+, if the sensor is known to stay
+    /// below half of the reference resistance, e.g. below 266 C° for a PT100
+    /// with a 400 Ohm reference. Use this together with the fault status to
+    /// pinpoint wiring problems.
+    pub fn read_code_signed(&mut self) -> Result<i16, Error<E>> {
+        let raw = self.read_raw()?;
+
+        Ok((raw as i16) >> 1)
+    }
+
+    /// Read the raw RTD value, rejecting patterns that indicate the device is
+    /// not connected.
+    ///
+    /// # Remarks
+    ///
+    /// Behaves like `read_raw`, but returns `Error::SuspiciousReading` if the
+    /// value is `0x0000` or `0xFFFF`. These are the patterns read from a
+    /// floating or disconnected MISO line and are not plausible readings from
+    /// a connected sensor.
+    pub fn read_raw_validated(&mut self) -> Result<u16, Error<E>> {
+        match self.read_raw()? {
+            0x0000 | 0xFFFF => Err(Error::SuspiciousReading),
+            raw => Ok(raw),
+        }
+    }
+
+    /// Estimate whether the mains filter matches the local mains frequency.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the conversions.
+    /// * `samples` - The number of consecutive conversions taken with each
+    ///               filter, at least `2`.
+    ///
+    /// # Remarks
+    ///
+    /// Takes `samples` conversions with the 50Hz filter and then with the 60Hz
+    /// filter and measures the ripple of each as the mean absolute change
+    /// between consecutive ADC codes. Mains interference the filter doesn't
+    /// reject shows up as ripple, as it is aliased by the conversion rate. The
+    /// filter with less ripple is recommended if the difference exceeds the
+    /// ADC noise (`ADC_NOISE_LSB`). The previous filter is restored
+    /// afterwards.
+    ///
+    /// *Note*: The device must be configured for automatic conversion mode
+    /// and the temperature should be stable while sampling, as any change of
+    /// the temperature adds to the ripple. At least 16 samples are
+    /// recommended; each takes one conversion period (about 20ms).
+    ///
+    /// If enabled with `set_filter_auto_switch`, the filter is switched to
+    /// the recommended one instead of restoring the previous filter. The
+    /// filter configured afterwards is reported by `MainsEstimate::settled`
+    /// and a switch by `MainsEstimate::switched`.
+    pub fn detect_mains_interference(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        samples: usize,
+    ) -> Result<MainsEstimate, Error<E>> {
+        let previous = if self.config & CONFIG_FILTER_MODE != 0 {
+            FilterMode::Filter50Hz
+        } else {
+            FilterMode::Filter60Hz
+        };
+
+        let ripple = self
+            .measure_ripple(FilterMode::Filter50Hz, delay, samples)
+            .and_then(|ripple_50hz| {
+                let ripple_60hz = self.measure_ripple(FilterMode::Filter60Hz, delay, samples)?;
+                Ok((ripple_50hz, ripple_60hz))
+            });
+        let restored = self.set_filter_mode(previous, delay);
+
+        let (ripple_50hz, ripple_60hz) = ripple?;
+        restored?;
+
+        let recommended = if ripple_50hz + ADC_NOISE_LSB < ripple_60hz {
+            Some(FilterMode::Filter50Hz)
+        } else if ripple_60hz + ADC_NOISE_LSB < ripple_50hz {
+            Some(FilterMode::Filter60Hz)
+        } else {
+            None
+        };
+
+        let switch = match recommended {
+            Some(filter_mode) if self.filter_auto_switch => filter_mode != previous,
+            _ => false,
+        };
+        let settled = match recommended {
+            Some(filter_mode) if switch => {
+                self.set_filter_mode(filter_mode, delay)?;
+                filter_mode
+            }
+            _ => previous,
+        };
+
+        Ok(MainsEstimate {
+            ripple_50hz,
+            ripple_60hz,
+            recommended,
+            settled,
+            switched: switch,
+        })
+    }
+
+    /// Switch the filter automatically in `detect_mains_interference`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to switch to the recommended filter, `false` (the
+    ///               default) to only report it.
+    ///
+    /// # Remarks
+    ///
+    /// The driver defaults to the 60Hz filter unless configured otherwise.
+    /// With this enabled, running `detect_mains_interference` once after
+    /// installation selects the filter matching the local mains frequency.
+    /// The filter is changed with the sequence of `set_filter_mode`.
+    pub fn set_filter_auto_switch(&mut self, enabled: bool) {
+        self.filter_auto_switch = enabled;
+    }
+
+    fn measure_ripple(
+        &mut self,
+        filter_mode: FilterMode,
+        delay: &mut impl DelayUs<u32>,
+        samples: usize,
+    ) -> Result<u32, Error<E>> {
+        self.set_filter_mode(filter_mode, delay)?;
+
+        let mut previous: Option<i32> = None;
+        let mut total = 0;
+        for _ in 0..samples {
+            self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+            let code = (self.read_raw()? >> 1) as i32;
+            if let Some(previous) = previous {
+                total += (code - previous).unsigned_abs();
+            }
+            previous = Some(code);
+        }
+
+        Ok(total / samples.saturating_sub(1).max(1) as u32)
+    }
+
+    /// Check whether the registers hold the power-on reset values of a
+    /// MAX31865.
+    ///
+    /// # Remarks
+    ///
+    /// The MAX31865 has no identification register, but after power-on or a
+    /// reset its registers read as `RESET_REGISTERS`: the configuration, RTD
+    /// and low fault threshold registers are `0x00` and the high fault
+    /// threshold registers are `0xFF`, i.e. thresholds of `0x7FFF` and
+    /// `0x0000`. A floating bus or a different chip is unlikely to match. This
+    /// reads all registers in one burst and returns whether they match.
+    ///
+    /// *Note*: This is only reliable immediately after power-on or a reset,
+    /// before the driver has been configured. Any configuration or conversion
+    /// changes the registers and makes this return `false`.
+    pub fn identify(&mut self) -> Result<bool, Error<E>> {
+        let mut registers = [0u8; REGISTER_COUNT];
+        self.read_burst(Register::CONFIG, REGISTER_COUNT, &mut registers)?;
+
+        Ok(registers == RESET_REGISTERS)
+    }
+
+    /// Check for bit errors caused by an overclocked SPI bus.
+    ///
+    /// # Remarks
+    ///
+    /// This reads the high fault threshold registers, which the device never
+    /// changes on its own, several times and returns `Error::BusSpeedSuspect`
+    /// if the reads disagree. This is the typical symptom of an SPI clock
+    /// above `MAX_SPI_HZ` or of poor signal integrity. A passing check does
+    /// not guarantee the bus is error free.
+    pub fn check_bus_speed(&mut self) -> Result<(), Error<E>> {
+        let mut first = [0u8; 2];
+        self.read_burst(Register::HIGH_FAULT_THRESHOLD_MSB, 2, &mut first)?;
+
+        for _ in 1..BUS_CHECK_READS {
+            let mut buffer = [0u8; 2];
+            self.read_burst(Register::HIGH_FAULT_THRESHOLD_MSB, 2, &mut buffer)?;
+            if buffer != first {
+                return Err(Error::BusSpeedSuspect);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the fault status register.
+    ///
+    /// # Remarks
+    ///
+    /// Faults are latched by the device until they are cleared by writing
+    /// the fault status clear bit of the configuration register.
+    pub fn read_fault_status(&mut self) -> Result<FaultStatus, Error<E>> {
+        Ok(FaultStatus(self.read(Register::FAULT_STATUS)?))
+    }
+
+    /// Determine if a new conversion is available
+    ///
+    /// # Remarks
+    ///
+    /// When the module is finished converting the temperature it sets the
+    /// ready pin to low. It is automatically returned to high upon reading the
+    /// RTD registers.
+    ///
+    /// A failure to read the ready pin is reported as `Error::PinError`. All
+    /// of the waiting methods poll the ready pin through this method.
+    pub fn is_ready(&self) -> Result<bool, Error<E>> {
+        self.rdy.is_low().map_err(|_| Error::PinError)
+    }
+
+    /// Read the temperature if a new conversion is available, for
+    /// cooperative schedulers.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `Poll::Pending` if the ready pin is not asserted, and otherwise
+    /// `Poll::Ready` with the temperature in degrees Celsius multiplied by
+    /// 100, see `read_default_conversion`. This never blocks, so it can be
+    /// called from a custom event loop until the reading is ready.
+    pub fn poll_read(&mut self) -> Result<Poll<i32>, Error<E>> {
+        if !self.is_ready()? {
+            return Ok(Poll::Pending);
+        }
+
+        self.read_default_conversion().map(Poll::Ready)
+    }
+
+    /// Wait for a new conversion to become available, giving up after a
+    /// bounded amount of time.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait between polls of the ready
+    ///             pin.
+    /// * `timeout_ms` - The maximum time in milliseconds to wait for the ready
+    ///                  pin to be asserted.
+    ///
+    /// # Remarks
+    ///
+    /// The ready pin is polled with `is_ready` once every millisecond. If it
+    /// has not been asserted once `timeout_ms` has elapsed, `Error::Timeout`
+    /// is returned. This indicates that either the ready pin is miswired or
+    /// the device is not converting (e.g. V_BIAS or conversion mode disabled).
+    ///
+    /// If the ready fallback is enabled, see `set_ready_fallback`, this
+    /// instead gives up waiting after the conversion time and returns `Ok`.
+    pub fn wait_for_ready(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        timeout_ms: u32,
+    ) -> Result<(), Error<E>> {
+        self.wait_ready(delay, timeout_ms, &mut || {})
+    }
+
+    /// Wait for a new conversion to become available, returning
+    /// `Error::Timeout` if the ready pin is not asserted within `timeout_ms`.
+    ///
+    /// This is equivalent to `wait_for_ready`.
+    pub fn wait_for_ready_timeout(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        timeout_ms: u32,
+    ) -> Result<(), Error<E>> {
+        self.wait_ready(delay, timeout_ms, &mut || {})
+    }
+
+    /// Wait for the next conversion and read the temperature, calling `feed`
+    /// while waiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait between polls of the ready
+    ///             pin.
+    /// * `feed` - Called on every poll of the ready pin, e.g. to feed a
+    ///            hardware watchdog.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// The ready pin is polled once every millisecond, so `feed` is called at
+    /// least that often. If no conversion completes within the maximum
+    /// conversion time, `Error::Timeout` is returned rather than feeding the
+    /// watchdog forever.
+    pub fn read_blocking_with_feed(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        feed: &mut impl FnMut(),
+    ) -> Result<i32, Error<E>> {
+        self.wait_ready(delay, CONVERSION_TIMEOUT_MS, feed)?;
+        self.read_default_conversion()
+    }
+
+    /// Wait for the next conversion and read the temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait between polls of the ready
+    ///             pin.
+    ///
+    /// # Remarks
+    ///
+    /// Behaves like `read_blocking_with_feed`, but reports whether the
+    /// conversion was confirmed by the ready pin, see `set_ready_fallback`.
+    pub fn read_blocking(&mut self, delay: &mut impl DelayUs<u32>) -> Result<Reading, Error<E>> {
+        self.wait_ready(delay, CONVERSION_TIMEOUT_MS, &mut || {})?;
+
+        Ok(Reading {
+            temperature: self.read_default_conversion()?,
+            unconfirmed: self.ready_timed_out,
+        })
+    }
+
+    /// Read a block of consecutive conversions, e.g. for frequency analysis.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The buffer to fill with temperatures in degrees Celsius
+    ///           multiplied by 100.
+    /// * `delay` - A delay provider used to wait between polls of the ready
+    ///             pin.
+    ///
+    /// # Remarks
+    ///
+    /// Waits for each conversion as `read_blocking_with_feed` does, so this
+    /// blocks for `buf.len()` times the conversion time, which in automatic
+    /// conversion mode is about 16.7 ms with the 60 Hz filter and 20 ms with
+    /// the 50 Hz filter.
+    ///
+    /// Returns the number of samples captured. If an error occurs, capture
+    /// stops and the samples captured so far are returned, unless there are
+    /// none in which case the error is returned.
+    pub fn read_block(
+        &mut self,
+        buf: &mut [i32],
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<usize, Error<E>> {
+        for (captured, sample) in buf.iter_mut().enumerate() {
+            let result = self
+                .wait_ready(delay, CONVERSION_TIMEOUT_MS, &mut || {})
+                .and_then(|_| self.read_default_conversion());
+
+            match result {
+                Ok(temp) => *sample = temp,
+                Err(e) if captured == 0 => return Err(e),
+                Err(_) => return Ok(captured),
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Read an average of several conversions with the extremes discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of conversions to take, at least `3`. Smaller
+    ///         values are treated as `3`.
+    /// * `delay` - A delay provider used to wait between polls of the ready
+    ///             pin.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100.
+    ///
+    /// Takes `n` consecutive conversions, discards the highest and the lowest
+    /// and returns the mean of the rest, rounded to the nearest hundredth.
+    /// This rejects a single spike, e.g. from EMI, which a plain average would
+    /// smear into the result. This blocks for `n` conversion times, see
+    /// `read_block`.
+    pub fn read_robust_average(
+        &mut self,
+        n: u8,
+        delay: &mut impl DelayUs<u32>,
+    ) -> Result<i32, Error<E>> {
+        let n = n.max(3);
+        let mut total = 0;
+        let mut lowest = i32::MAX;
+        let mut highest = i32::MIN;
+
+        for _ in 0..n {
+            self.wait_for_ready(delay, CONVERSION_TIMEOUT_MS)?;
+            let temp = self.read_default_conversion()?;
+
+            total += temp;
+            lowest = lowest.min(temp);
+            highest = highest.max(temp);
+        }
+
+        Ok(RoundingMode::HalfUp.divide(total - lowest - highest, n as i32 - 2))
+    }
+
+    /// Proceed to read when the ready pin is not asserted in time.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to read the registers anyway, `false` (the
+    ///               default) to return `Error::Timeout`.
+    ///
+    /// # Remarks
+    ///
+    /// Some boards have a miswired ready pin or a dominating pull-up, so that
+    /// the ready pin is never asserted. With the fallback enabled, the
+    /// blocking read methods stop waiting once the maximum conversion time
+    /// for the configured filter plus a margin has elapsed and read the
+    /// registers anyway. Whether this happened during the last wait is
+    /// reported by `ready_timed_out` and by `Reading::unconfirmed`.
+    pub fn set_ready_fallback(&mut self, enabled: bool) {
+        self.ready_fallback = enabled;
+    }
+
+    /// Whether the last wait for the ready pin gave up and proceeded anyway,
+    /// see `set_ready_fallback`.
+    pub fn ready_timed_out(&self) -> bool {
+        self.ready_timed_out
+    }
+
+    fn wait_ready(
+        &mut self,
+        delay: &mut impl DelayUs<u32>,
+        timeout_ms: u32,
+        feed: &mut impl FnMut(),
+    ) -> Result<(), Error<E>> {
+        let timeout_ms = if self.ready_fallback {
+            let conversion_ms = if self.config & CONFIG_FILTER_MODE != 0 {
+                CONVERSION_TIME_50HZ_MS
+            } else {
+                CONVERSION_TIME_60HZ_MS
+            };
+            timeout_ms.min(conversion_ms + READY_FALLBACK_MARGIN_MS)
+        } else {
+            timeout_ms
+        };
+        let mut waited_ms = 0;
+        self.ready_timed_out = false;
+
+        loop {
+            feed();
+
+            if self.is_ready()? {
+                return Ok(());
+            }
+
+            if waited_ms >= timeout_ms {
+                if self.ready_fallback {
+                    self.ready_timed_out = true;
+                    return Ok(());
+                }
+                return Err(Error::Timeout);
+            }
+
+            delay.delay_us(1_000);
+            waited_ms += 1;
+        }
+    }
+
+    fn check_bias(&mut self) -> Result<(), Error<E>> {
+        if self.bias_check_interval == 0 {
+            return Ok(());
+        }
+
+        self.reads_since_bias_check += 1;
+        if self.reads_since_bias_check < self.bias_check_interval {
+            return Ok(());
+        }
+        self.reads_since_bias_check = 0;
+
+        if self.read(Register::CONFIG)? & CONFIG_VBIAS == 0 {
+            return Err(Error::BiasDisabled);
+        }
+
+        Ok(())
+    }
+
+    /// The resistance domain corrections, steps 1 to 3 of the correction
+    /// pipeline described in the crate documentation.
+    fn correct_resistance(&self, raw: u16) -> u32 {
+        let ohms = self.code_to_resistance(raw >> 1, 100);
+
+        self.correct_scaled_resistance(ohms, 100)
+    }
+
+    /// Step 1 of the correction pipeline, converting a 15 bit ADC code to a
+    /// resistance in Ohms multiplied by `scale`, which must be either 100 or
+    /// 1000.
+    fn code_to_resistance(&self, code: u16, scale: u64) -> u64 {
+        let resistance = match self.reference_wiring {
+            RefWiring::Standard if scale == 1000 => {
+                conversion::code_to_milliohms(code, self.calibration)
+            }
+            RefWiring::Standard => conversion::code_to_ohms100(code, self.calibration),
+            RefWiring::Swapped if scale == 1000 => conversion::swapped_code_to_milliohms(
+                code,
+                self.calibration,
+                self.max_table_resistance().saturating_mul(10),
+            ),
+            RefWiring::Swapped => conversion::swapped_code_to_ohms100(
+                code,
+                self.calibration,
+                self.max_table_resistance(),
+            ),
+        };
+
+        resistance as u64
+    }
+
+    /// The calibration, i.e. the reference resistance in Ohms multiplied by
+    /// 100, for which `code` converts to `ohms`, the inverse of
+    /// `code_to_resistance` with respect to the reference.
+    fn calibration_for(&self, code: u16, ohms: u32) -> u32 {
+        match self.reference_wiring {
+            RefWiring::Standard => conversion::swapped_code_to_ohms100(code, ohms, u32::MAX),
+            RefWiring::Swapped => conversion::code_to_ohms100(code, ohms),
+        }
+    }
+
+    /// The resistance of the last entry of the lookup table of the RTD curve
+    /// in Ohms multiplied by 100.
+    fn max_table_resistance(&self) -> u32 {
+        self.curve.lookup_table().resistance_range().1.max(0) as u32
+    }
+
+    /// Steps 2 and 3 of the correction pipeline for a resistance in Ohms
+    /// multiplied by `scale`, which must be a multiple of 100.
+    fn correct_scaled_resistance(&self, ohms: u64, scale: u64) -> u32 {
+        let factor = scale as i64 / 100;
+        let mut ohms = ohms as i64;
+
+        if self.wire_count == WireCount::Two {
+            ohms = (ohms - self.lead_resistance as i64 * factor).max(0);
+        }
+
+        if let Some(points) = self.two_point {
+            ohms = conversion::two_point_correction(ohms, points, factor);
+        }
+
+        ohms.clamp(0, MAX_CALIBRATION as i64 * factor) as u32
+    }
+
+    /// The inverse of the correction pipeline, converting a temperature to
+    /// the ADC code it would be read as.
+    fn temperature_to_code(&self, celsius_100: i32) -> u16 {
+        let ohms = self.temperature_to_measured_ohms(celsius_100);
+
+        match self.reference_wiring {
+            RefWiring::Standard => conversion::ohms100_to_code(ohms, self.calibration),
+            RefWiring::Swapped => conversion::ohms100_to_swapped_code(ohms, self.calibration),
+        }
+    }
+
+    /// The inverse of the correction pipeline up to the calibration, i.e.
+    /// the resistance in ohms multiplied by 100 the ADC would measure at the
+    /// given temperature.
+    fn temperature_to_measured_ohms(&self, celsius_100: i32) -> u32 {
+        let table = self.curve.lookup_table();
+        let mut ohms = table.lookup_resistance(celsius_100 - self.offset).max(0) as i64;
+
+        if let Some([(m_lo, a_lo), (m_hi, a_hi)]) = self.two_point {
+            if a_hi != a_lo {
+                ohms = m_lo as i64
+                    + (ohms - a_lo as i64) * (m_hi as i64 - m_lo as i64)
+                        / (a_hi as i64 - a_lo as i64);
+            }
+        }
+
+        if self.wire_count == WireCount::Two {
+            ohms += self.lead_resistance as i64;
+        }
+
+        ohms.clamp(0, u32::MAX as i64) as u32
+    }
+
+    /// The full correction pipeline described in the crate documentation,
+    /// shared by all methods returning a temperature.
+    fn apply_corrections(&self, raw: u16, table: &LookupTable<u32>) -> i32 {
+        debug_assert!(
+            self.config & CONFIG_VBIAS != 0,
+            "MAX31865: configure() must be called with vbias enabled before reading"
+        );
+
+        let ohms = self.correct_resistance(raw);
+        let temp = table.lookup_temperature(ohms as i32);
+
+        temp + self.offset
+    }
+
+    /// The full correction pipeline like `apply_corrections`, but returning
+    /// the temperature in millidegrees Celsius.
+    fn apply_corrections_milli(&self, raw: u16) -> i32 {
+        let milliohms = self.code_to_resistance(raw >> 1, 1000);
+        let milliohms = self.correct_scaled_resistance(milliohms, 1000);
+        let temp = self
+            .curve
+            .lookup_table()
+            .lookup_temperature_milli(milliohms as i32);
+
+        temp + self.offset * 10
+    }
+
+    /// Trace every SPI transaction, e.g. to debug wiring or byte order
+    /// issues.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace` - Called after every SPI transaction with the bytes sent and
+    ///             the bytes received. The received bytes are empty for
+    ///             writes.
+    ///
+    /// # Remarks
+    ///
+    /// The closure may capture state, e.g. a buffer or a logger handle. It is
+    /// borrowed for the lifetime of the program so that no allocator is
+    /// needed and the type of the driver doesn't depend on it, e.g. place it
+    /// in a `static` cell or leak a `Box` when `std` is available.
+    /// Transactions which fail are not traced.
+    ///
+    /// *Note*: This requires the `trace` feature, without it there is no
+    /// overhead.
+    #[cfg(feature = "trace")]
+    pub fn set_trace(&mut self, trace: TraceFn) {
+        self.trace = Some(trace);
+    }
+
+    /// Stop tracing SPI transactions, see `set_trace`.
+    ///
+    /// *Note*: This requires the `trace` feature.
+    #[cfg(feature = "trace")]
+    pub fn clear_trace(&mut self) {
+        self.trace = None;
+    }
+
+    #[cfg(feature = "trace")]
+    fn trace_transaction(&mut self, sent: &[u8], received: &[u8]) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace(sent, received);
+        }
+    }
+
+    fn read(&mut self, reg: Register) -> Result<u8, Error<E>> {
+        let mut buffer = [0u8; 1];
+        self.read_burst(reg, 1, &mut buffer)?;
+        Ok(buffer[0])
+    }
+
+    /// Read `len` consecutive registers starting at `start_reg` into the
+    /// start of `buf`, using the auto-increment of the register address.
+    ///
+    /// Returns `Error::BufferTooSmall` if `buf` is shorter than `len` or more
+    /// registers are requested than the device has.
+    fn read_burst(
+        &mut self,
+        start_reg: Register,
+        len: usize,
+        buf: &mut [u8],
+    ) -> Result<(), Error<E>> {
+        if len > buf.len() || len > REGISTER_COUNT {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let mut buffer = [0u8; REGISTER_COUNT + 1];
+        let buffer = &mut buffer[..=len];
+        buffer[0] = start_reg.read_address();
+        #[cfg(feature = "trace")]
+        let sent = {
+            let mut sent = [0u8; REGISTER_COUNT + 1];
+            sent[0] = start_reg.read_address();
+            sent
+        };
+
+        self.ncs.set_low().map_err(|_| Error::PinError)?;
+        let result = match self.spi.transfer(buffer) {
+            Ok(received) if received.len() == len + 1 => {
+                #[cfg(feature = "trace")]
+                self.trace_transaction(&sent[..=len], received);
+                buf[..len].copy_from_slice(&received[1..]);
+                Ok(())
+            }
+            Ok(_) => Err(Error::IncompleteTransfer),
+            Err(e) => Err(self.spi_error(e)),
+        };
+        self.ncs.set_high().map_err(|_| Error::PinError)?;
+
+        result
+    }
+
+    /// Write `values` to consecutive registers starting at `start_reg`, using
+    /// the auto-increment of the register address.
+    ///
+    /// Only the configuration register (`0x00`) and the fault threshold
+    /// registers (`0x03` to `0x06`) are writable, so a burst must stay within
+    /// one of these ranges. Returns `Error::BufferTooSmall` if more registers
+    /// are written than the device has.
+    fn write_burst(&mut self, start_reg: Register, values: &[u8]) -> Result<(), Error<E>> {
+        if values.len() > REGISTER_COUNT {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let mut buffer = [0u8; REGISTER_COUNT + 1];
+        let buffer = &mut buffer[..=values.len()];
+        buffer[0] = start_reg.write_address();
+        buffer[1..].copy_from_slice(values);
+
+        self.ncs.set_low().map_err(|_| Error::PinError)?;
+        let result = self.spi.write(buffer).map_err(|e| self.spi_error(e));
+        self.ncs.set_high().map_err(|_| Error::PinError)?;
+        #[cfg(feature = "trace")]
+        if result.is_ok() {
+            self.trace_transaction(buffer, &[]);
+        }
+
+        result
+    }
+
+    fn write(&mut self, reg: Register, val: u8) -> Result<(), Error<E>> {
+        self.ncs.set_low().map_err(|_| Error::PinError)?;
+        self.spi
+            .write(&[reg.write_address(), val])
+            .map_err(|e| self.spi_error(e))?;
+        self.ncs.set_high().map_err(|_| Error::PinError)?;
+        #[cfg(feature = "trace")]
+        self.trace_transaction(&[reg.write_address(), val], &[]);
+        Ok(())
+    }
+}
+
+/// Read the temperature from the first device with a conversion available.
+///
+/// # Arguments
+///
+/// * `devices` - The devices to scan, e.g. the channels of a multi-sensor
+///               board.
+///
+/// # Remarks
+///
+/// Returns the index of the device and its temperature in degrees Celsius
+/// multiplied by 100, or `None` if no device is ready.
+///
+/// The devices are checked in order, starting at index `0` on every call, and
+/// only the first ready device is read. Reading a device releases its ready
+/// pin until its next conversion completes, so when called repeatedly in
+/// automatic conversion mode every device is eventually served. Lower indices
+/// are however served first, so if the devices are polled less often than
+/// they convert, later devices see older readings.
+pub fn poll_any<E, SPI, NCS, RDY, C>(
+    devices: &mut [Max31865<SPI, NCS, RDY, C>],
+) -> Result<Option<(usize, i32)>, Error<E>>
+where
+    SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    RDY: InputPin,
+    C: RtdCurve,
+{
+    for (index, device) in devices.iter_mut().enumerate() {
+        if device.is_ready()? {
+            return device
+                .read_default_conversion()
+                .map(|temp| Some((index, temp)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Convert a block of raw RTD values to temperatures, e.g. values collected by
+/// an interrupt handler with `Max31865::read_raw`.
+///
+/// # Arguments
+///
+/// * `raw` - The raw RTD values including the fault bit.
+/// * `out` - The buffer to write the temperatures to.
+/// * `calibration` - The calibrated reference resistance in ohms multiplied
+///                   by 100, see `Max31865::set_calibration`.
+/// * `wiring` - The wiring of the reference resistor, see
+///              `Max31865::set_reference_wiring`.
+/// * `rtd` - The type of the RTD the values were measured with.
+///
+/// # Remarks
+///
+/// The output values are in degrees Celsius multiplied by 100. Values with
+/// the fault bit set are converted to `FAULTED_SAMPLE` instead. Only the
+/// first `min(raw.len(), out.len())` values are converted.
+///
+/// This uses the same conversion as the driver, but without the lead
+/// resistance, two-point and offset corrections, which are settings of a
+/// driver instance.
+pub fn convert_block(raw: &[u16], out: &mut [i32], calibration: u32, wiring: RefWiring, rtd: Rtd) {
+    let table = rtd.lookup_table();
+    let max_ohms = table.resistance_range().1.max(0) as u32;
+
+    for (raw, out) in raw.iter().zip(out.iter_mut()) {
+        *out = if raw & 1 != 0 {
+            FAULTED_SAMPLE
+        } else {
+            let ohms = match wiring {
+                RefWiring::Standard => conversion::code_to_ohms100(raw >> 1, calibration),
+                RefWiring::Swapped => {
+                    conversion::swapped_code_to_ohms100(raw >> 1, calibration, max_ohms)
+                }
+            };
+            table.lookup_temperature(ohms.min(MAX_CALIBRATION) as i32)
+        };
+    }
+}
+
+/// The value `convert_block` outputs for samples with the fault bit set.
+pub const FAULTED_SAMPLE: i32 = i32::MIN;
+
+/// The largest calibration accepted by `Max31865::try_set_calibration`, in
+/// ohms multiplied by 100.
+///
+/// The temperature lookup interpolates in 32 bits and multiplies the
+/// distance of the resistance from a table entry by the table step in
+/// hundredths of a degree, `2000` for the built-in tables, and
+/// `2000 * 1_000_000` stays below `i32::MAX`. The driver therefore limits
+/// every corrected resistance to this value before the lookup, which only
+/// affects readings with a larger calibration or a two-point calibration
+/// mapping them beyond it. `convert_block` limits its resistances likewise.
+pub const MAX_CALIBRATION: u32 = 1_000_000;
+
+/// The number of bytes written by `Max31865::read_into`.
+pub const READING_LEN: usize = 3;
+
+/// The length of the blob produced by `Max31865::export_calibration`.
+pub const CALIBRATION_BLOB_LEN: usize = 39;
+
+const CALIBRATION_BLOB_VERSION: u8 = 2;
+
+const CALIBRATION_FLAG_SWAPPED: u8 = 1 << 0;
+const CALIBRATION_FLAG_TWO_POINT: u8 = 1 << 1;
+const CALIBRATION_FLAG_RANGE: u8 = 1 << 2;
+const CALIBRATION_FLAGS: u8 =
+    CALIBRATION_FLAG_SWAPPED | CALIBRATION_FLAG_TWO_POINT | CALIBRATION_FLAG_RANGE;
+
+fn calibration_checksum(bytes: &[u8]) -> u8 {
+    !bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+fn le_u32(bytes: &[u8]) -> u32 {
+    let mut word = [0u8; 4];
+    word.copy_from_slice(bytes);
+    u32::from_le_bytes(word)
+}
+
+/// Whether the points of a two-point calibration are accepted by
+/// `Max31865::set_two_point_calibration`.
+fn valid_two_point(low: (u32, u32), high: (u32, u32)) -> bool {
+    low.0 != high.0 && low.1 <= MAX_CALIBRATION && high.1 <= MAX_CALIBRATION
+}
+
+/// The state of a non-blocking read of the RTD registers.
+#[derive(Clone, Copy)]
+struct NbTransfer {
+    buffer: [u8; 3],
+    sent: usize,
+    received: usize,
+}
+
+impl<E, SPI, NCS, RDY, C> Max31865<SPI, NCS, RDY, C>
+where
+    SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E> + FullDuplex<u8, Error = E>,
+    NCS: OutputPin,
+    RDY: InputPin,
+    C: RtdCurve,
+{
+    /// Start a non-blocking read of the raw RTD value.
+    ///
+    /// # Remarks
+    ///
+    /// This selects the device and starts shifting out the read of the RTD
+    /// registers using the non-blocking `FullDuplex` SPI trait. Afterwards
+    /// call `finish_read` until it no longer returns `nb::Error::WouldBlock`,
+    /// doing other work in between:
+    ///
+    /// ```ignore
+    /// max31865.start_read()?;
+    /// let raw = loop {
+    ///     match max31865.finish_read() {
+    ///         Err(nb::Error::WouldBlock) => do_other_work(),
+    ///         result => break result,
+    ///     }
+    /// };
+    /// ```
+    ///
+    /// The transfer buffer is owned by the driver, so the caller does not need
+    /// to keep any buffer alive. The SPI bus must not be used for anything
+    /// else until the read has finished.
+    pub fn start_read(&mut self) -> Result<(), Error<E>> {
+        let buffer = [Register::RTD_MSB.read_address(), 0, 0];
+
+        self.ncs.set_low().map_err(|_| Error::PinError)?;
+        let sent = match self.spi.send(buffer[0]) {
+            Ok(()) => 1,
+            Err(nb::Error::WouldBlock) => 0,
+            Err(nb::Error::Other(e)) => {
+                self.ncs.set_high().map_err(|_| Error::PinError)?;
+                return Err(self.spi_error(e));
+            }
+        };
+
+        self.transfer = Some(NbTransfer {
+            buffer,
+            sent,
+            received: 0,
+        });
+
+        Ok(())
+    }
+
+    /// Advance a read started with `start_read`.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `nb::Error::WouldBlock` while the transfer is still in
+    /// progress and the raw RTD value (see `read_raw`) once it has completed.
+    /// Returns `Error::NoReadPending` if no read has been started.
+    pub fn finish_read(&mut self) -> nb::Result<u16, Error<E>> {
+        let mut transfer = self
+            .transfer
+            .ok_or(nb::Error::Other(Error::NoReadPending))?;
+
+        let result = loop {
+            if transfer.received < transfer.sent {
+                match self.spi.read() {
+                    Ok(byte) => {
+                        transfer.buffer[transfer.received] = byte;
+                        transfer.received += 1;
+                    }
+                    Err(e) => break Err(e),
+                }
+            } else if transfer.sent < transfer.buffer.len() {
+                match self.spi.send(transfer.buffer[transfer.sent]) {
+                    Ok(()) => transfer.sent += 1,
+                    Err(e) => break Err(e),
+                }
+            } else {
+                break Ok(((transfer.buffer[1] as u16) << 8) | transfer.buffer[2] as u16);
+            }
+        };
+
+        match result {
+            Err(nb::Error::WouldBlock) => {
+                self.transfer = Some(transfer);
+                Err(nb::Error::WouldBlock)
+            }
+            Err(nb::Error::Other(e)) => {
+                self.transfer = None;
+                self.ncs.set_high().map_err(|_| Error::PinError)?;
+                Err(nb::Error::Other(self.spi_error(e)))
+            }
+            Ok(raw) => {
+                self.transfer = None;
+                self.ncs.set_high().map_err(|_| Error::PinError)?;
+                self.count_conversion(raw);
+                #[cfg(feature = "trace")]
+                self.trace_transaction(&[Register::RTD_MSB.read_address(), 0, 0], &transfer.buffer);
+                Ok(raw)
+            }
+        }
+    }
+}
+
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+enum Register {
+    CONFIG = 0x00,
+    RTD_MSB = 0x01,
+    RTD_LSB = 0x02,
+    HIGH_FAULT_THRESHOLD_MSB = 0x03,
+    HIGH_FAULT_THRESHOLD_LSB = 0x04,
+    LOW_FAULT_THRESHOLD_MSB = 0x05,
+    LOW_FAULT_THRESHOLD_LSB = 0x06,
+    FAULT_STATUS = 0x07,
+}
+
+/// The number of registers of the device, `CONFIG` to `FAULT_STATUS`.
+const REGISTER_COUNT: usize = 8;
+
+/// The power-on values of all registers, starting with the configuration
+/// register, see `Max31865::identify`.
+pub const RESET_REGISTERS: [u8; REGISTER_COUNT] = [0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00];
+
+const R: u8 = 0 << 7;
+const W: u8 = 1 << 7;
+
+const CONFIG_VBIAS: u8 = 1 << 7;
+const CONFIG_CONVERSION_MODE: u8 = 1 << 6;
+const CONFIG_ONE_SHOT: u8 = 1 << 5;
+const CONFIG_3WIRE: u8 = 1 << 4;
+const CONFIG_FAULT_DETECTION: u8 = 0b11 << 2;
+const CONFIG_FAULT_CLEAR: u8 = 1 << 1;
+const CONFIG_FILTER_MODE: u8 = 1;
+
+const FAULT_HIGH_THRESHOLD: u8 = 1 << 7;
+const FAULT_LOW_THRESHOLD: u8 = 1 << 6;
+
+/// Time to wait for an automatic conversion in progress to complete (the
+/// conversion period is 20ms when filtering 50Hz).
+const CONVERSION_SETTLE_US: u32 = 25_000;
+
+/// The assumed noise of the ADC in codes (LSB), used to estimate the
+/// uncertainty of a reading in `Max31865::read_with_uncertainty`.
+pub const ADC_NOISE_LSB: u32 = 2;
+
+/// The interval between readings of `Max31865::warm_up` in milliseconds.
+pub const WARM_UP_INTERVAL_MS: u32 = 100;
+
+/// The largest change between successive readings in degrees Celsius
+/// multiplied by 100 that `Max31865::warm_up` considers stable.
+pub const WARM_UP_THRESHOLD: i32 = 2;
+
+/// The default coefficient of the running average, see
+/// `Max31865::set_average_shift`.
+pub const DEFAULT_AVERAGE_SHIFT: u8 = 3;
+
+/// The default time to wait for V_BIAS to settle, see
+/// `Max31865::set_bias_settle_time_us`.
+pub const DEFAULT_BIAS_SETTLE_US: u32 = 10_000;
+
+/// Upper bound on the time a conversion takes (a one-shot conversion takes
+/// up to 66ms when filtering 50Hz).
+const CONVERSION_TIMEOUT_MS: u32 = 100;
+
+/// The smallest reference resistor to nominal RTD resistance ratio accepted by
+/// `Max31865::validate_reference_for`.
+pub const REFERENCE_RATIO_MIN: u32 = 3;
+
+/// The largest reference resistor to nominal RTD resistance ratio accepted by
+/// `Max31865::validate_reference_for`.
+pub const REFERENCE_RATIO_MAX: u32 = 6;
+
+fn reference_matches(reference: u32, nominal: u32) -> bool {
+    let reference = reference as u64;
+    let nominal = nominal as u64;
+
+    reference >= nominal * REFERENCE_RATIO_MIN as u64
+        && reference <= nominal * REFERENCE_RATIO_MAX as u64
+}
+
+/// The values of the high and low fault threshold registers, in register
+/// order, for the given 15 bit codes, which are saturated at `0x7FFF`.
+fn threshold_registers(low: u16, high: u16) -> [u8; 4] {
+    let [high_msb, high_lsb] = (high.min(0x7FFF) << 1).to_be_bytes();
+    let [low_msb, low_lsb] = (low.min(0x7FFF) << 1).to_be_bytes();
+
+    [high_msb, high_lsb, low_msb, low_lsb]
+}
+
+/// The number of repeated reads compared by `Max31865::check_bus_speed`.
+const BUS_CHECK_READS: usize = 8;
+
+/// The maximum time of a one-shot conversion when filtering 50Hz.
+const CONVERSION_TIME_50HZ_MS: u32 = 66;
+
+/// The maximum time of a one-shot conversion when filtering 60Hz.
+const CONVERSION_TIME_60HZ_MS: u32 = 55;
+
+/// Added to the conversion time before proceeding without the ready pin, see
+/// `Max31865::set_ready_fallback`.
+const READY_FALLBACK_MARGIN_MS: u32 = 10;
+
+impl Register {
+    fn read_address(&self) -> u8 {
+        *self as u8 | R
+    }
+
+    fn write_address(&self) -> u8 {
         *self as u8 | W
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::config::Config;
+    use super::conversion::Ohms;
+    use super::{
+        poll_any, DeviceStats, DynCurve, Error, FilterMode, Max31865, Pt100, Pt1000, RefWiring,
+        Rtd, SensorType, ToleranceClass, WireCount, CONFIG_FILTER_MODE, CONFIG_VBIAS,
+    };
+    use embedded_hal_mock::delay::MockNoop;
+    use embedded_hal_mock::pin::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+    type Driver = Max31865<SpiMock, PinMock, PinMock>;
+
+    /// Create a driver expecting the given SPI transactions, each of which is
+    /// surrounded by toggling the chip select line.
+    fn driver(spi: &[SpiTransaction], rdy: &[PinTransaction]) -> Driver {
+        let mut ncs = vec![PinTransaction::set(State::High)];
+        for _ in spi {
+            ncs.push(PinTransaction::set(State::Low));
+            ncs.push(PinTransaction::set(State::High));
+        }
+
+        let mut max =
+            Max31865::new(SpiMock::new(spi), PinMock::new(&ncs), PinMock::new(rdy)).unwrap();
+        max.config = CONFIG_VBIAS;
+        max
+    }
+
+    fn done(driver: Driver) {
+        let Max31865 {
+            mut spi,
+            mut ncs,
+            mut rdy,
+            ..
+        } = driver;
+        spi.done();
+        ncs.done();
+        rdy.done();
+    }
+
+    #[test]
+    fn set_filter_mode_in_auto_mode() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC0]),
+                SpiTransaction::write(vec![0x80, 0x80]),
+                SpiTransaction::write(vec![0x80, 0x81]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+            ],
+            &[],
+        );
+
+        max.set_filter_mode(FilterMode::Filter50Hz, &mut MockNoop::new())
+            .unwrap();
+        done(max);
+    }
+
+    #[test]
+    fn set_filter_mode_in_normally_off_mode() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0x81]),
+                SpiTransaction::write(vec![0x80, 0x80]),
+            ],
+            &[],
+        );
+
+        max.set_filter_mode(FilterMode::Filter60Hz, &mut MockNoop::new())
+            .unwrap();
+        done(max);
+    }
+
+    #[test]
+    fn calibration_round_trip() {
+        let mut max = driver(&[], &[]);
+        max.set_calibration(43_012);
+        max.set_lead_resistance(120);
+        max.set_temperature_offset(-35);
+        max.set_reference_wiring(RefWiring::Swapped);
+        max.set_two_point_calibration((10_000, 10_010), (13_851, 13_840))
+            .unwrap();
+        max.set_calibrated_range(-4_000, 15_000);
+        let blob = max.export_calibration();
+
+        let mut other = driver(&[], &[]);
+        other.import_calibration(&blob).unwrap();
+        assert_eq!(other.calibration, 43_012);
+        assert_eq!(other.lead_resistance, 120);
+        assert_eq!(other.offset, -35);
+        assert_eq!(other.reference_wiring, RefWiring::Swapped);
+        assert_eq!(other.two_point, Some([(10_000, 10_010), (13_851, 13_840)]));
+        assert_eq!(other.calibrated_range, Some((-4_000, 15_000)));
+
+        max.reset_calibration();
+        max.set_reference_wiring(RefWiring::Standard);
+        other.import_calibration(&max.export_calibration()).unwrap();
+        assert_eq!(other.reference_wiring, RefWiring::Standard);
+        assert_eq!(other.two_point, None);
+        assert_eq!(other.calibrated_range, None);
+        assert_eq!(other.offset, 0);
+
+        done(max);
+        done(other);
+    }
+
+    #[test]
+    fn calibration_import_rejects_corruption() {
+        let mut max = driver(&[], &[]);
+        max.set_calibration(43_012);
+        let mut blob = max.export_calibration();
+        blob[2] ^= 0x10;
+
+        max.set_calibration(40_000);
+        assert!(matches!(
+            max.import_calibration(&blob),
+            Err(Error::InvalidCalibration)
+        ));
+        assert!(matches!(
+            max.import_calibration(&blob[..4]),
+            Err(Error::InvalidCalibration)
+        ));
+        assert_eq!(max.calibration, 40_000);
+
+        done(max);
+    }
+
+    #[test]
+    fn calibration_import_validates_values() {
+        use super::{calibration_checksum, CALIBRATION_BLOB_LEN};
+
+        let resign = |mut blob: [u8; CALIBRATION_BLOB_LEN]| {
+            blob[38] = calibration_checksum(&blob[..38]);
+            blob
+        };
+        let mut max = driver(&[], &[]);
+        let blob = max.export_calibration();
+
+        let mut zero = blob;
+        zero[1..5].copy_from_slice(&0u32.to_le_bytes());
+        assert!(matches!(
+            max.import_calibration(&resign(zero)),
+            Err(Error::CalibrationOutOfRange)
+        ));
+
+        let mut too_large = blob;
+        too_large[1..5].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            max.import_calibration(&resign(too_large)),
+            Err(Error::CalibrationOutOfRange)
+        ));
+
+        let mut two_point = blob;
+        two_point[37] |= 1 << 1;
+        assert!(matches!(
+            max.import_calibration(&resign(two_point)),
+            Err(Error::InvalidCalibration)
+        ));
+
+        let mut flags = blob;
+        flags[37] = 0x80;
+        assert!(matches!(
+            max.import_calibration(&resign(flags)),
+            Err(Error::InvalidCalibration)
+        ));
+
+        let mut version = blob;
+        version[0] = 1;
+        assert!(matches!(
+            max.import_calibration(&resign(version)),
+            Err(Error::InvalidCalibration)
+        ));
+        assert_eq!(max.calibration, 40_000);
+
+        done(max);
+    }
+
+    #[test]
+    fn tare_offsets_reading() {
+        let rtd_100_ohm = [
+            SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+            SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+        ];
+        let mut max = driver(
+            &[&rtd_100_ohm[..], &rtd_100_ohm[..]].concat(),
+            &[PinTransaction::get(State::Low)],
+        );
+
+        max.tare(2_500, &mut MockNoop::new()).unwrap();
+        assert_eq!(max.read_default_conversion().unwrap(), 2_500);
+        done(max);
+    }
+
+    #[test]
+    fn checked_read_retries_transient_fault() {
+        let mut max = driver(
+            &[
+                SpiTransaction::write(vec![0x80, 0xC0]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.configure(
+            true,
+            true,
+            false,
+            SensorType::TwoOrFourWire,
+            FilterMode::Filter60Hz,
+        )
+        .unwrap();
+        assert_eq!(
+            max.read_default_conversion_checked(&mut MockNoop::new())
+                .unwrap(),
+            0
+        );
+        done(max);
+    }
+
+    #[test]
+    fn checked_read_reports_persistent_fault() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+            ],
+            &[],
+        );
+
+        assert!(matches!(
+            max.read_default_conversion_checked(&mut MockNoop::new()),
+            Err(Error::ConversionFault)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn non_blocking_read() {
+        let spi = SpiMock::new(&[
+            SpiTransaction::send(0x01),
+            SpiTransaction::read(0x00),
+            SpiTransaction::send(0x00),
+            SpiTransaction::read(0x40),
+            SpiTransaction::send(0x00),
+            SpiTransaction::read(0x02),
+        ]);
+        let ncs = PinMock::new(&[
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+        ]);
+        let mut max = Max31865::new(spi, ncs, PinMock::new(&[])).unwrap();
+
+        max.start_read().unwrap();
+        assert_eq!(max.finish_read().unwrap(), 0x4002);
+        assert!(matches!(
+            max.finish_read(),
+            Err(nb::Error::Other(Error::NoReadPending))
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn uncertainty_from_adc_noise() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        // 2 LSB at a 400 Ohm reference round up to 0.03 Ohms, ~0.08 C° at 0 C°
+        assert_eq!(max.read_with_uncertainty().unwrap(), (0, 8));
+        done(max);
+    }
+
+    #[test]
+    fn lead_resistance_only_for_two_wires() {
+        let rtd_100_ohm = [
+            SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+            SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+        ];
+        let mut max = driver(
+            &[
+                &rtd_100_ohm[..],
+                &[
+                    SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0x81]),
+                    SpiTransaction::write(vec![0x80, 0x91]),
+                ],
+                &rtd_100_ohm[..],
+            ]
+            .concat(),
+            &[],
+        );
+
+        max.set_lead_resistance(100);
+        assert_eq!(max.read_ohms().unwrap(), Ohms(9_900));
+        max.set_wire_count(WireCount::Three).unwrap();
+        assert_eq!(max.read_ohms_raw().unwrap(), 10_000);
+        done(max);
+    }
+
+    /// An SPI bus whose transfers only exchange the first byte.
+    struct ShortSpi;
+
+    impl embedded_hal::blocking::spi::Transfer<u8> for ShortSpi {
+        type Error = ();
+
+        fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], ()> {
+            Ok(&words[..1])
+        }
+    }
+
+    impl embedded_hal::blocking::spi::Write<u8> for ShortSpi {
+        type Error = ();
+
+        fn write(&mut self, _words: &[u8]) -> Result<(), ()> {
+            Ok(())
+        }
+    }
+
+    /// An SPI bus on which every transfer fails.
+    struct FailingSpi;
+
+    impl embedded_hal::blocking::spi::Transfer<u8> for FailingSpi {
+        type Error = ();
+
+        fn transfer<'w>(&mut self, _words: &'w mut [u8]) -> Result<&'w [u8], ()> {
+            Err(())
+        }
+    }
+
+    impl embedded_hal::blocking::spi::Write<u8> for FailingSpi {
+        type Error = ();
+
+        fn write(&mut self, _words: &[u8]) -> Result<(), ()> {
+            Err(())
+        }
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn trace() {
+        use std::sync::{Arc, Mutex};
+
+        let traced = Arc::new(Mutex::new(Vec::new()));
+        let log = traced.clone();
+        let record = move |sent: &[u8], received: &[u8]| {
+            log.lock().unwrap().push((sent.to_vec(), received.to_vec()));
+        };
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::write(vec![0x80, 0xD1]),
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xD1]),
+            ],
+            &[],
+        );
+
+        max.set_trace(Box::leak(Box::new(record)));
+        max.read_raw_coarse().unwrap();
+        max.write_config_raw(0xD1).unwrap();
+        max.clear_trace();
+        max.read_config_raw().unwrap();
+        assert_eq!(
+            *traced.lock().unwrap(),
+            [
+                (vec![0x01, 0x00], vec![0x00, 0x40]),
+                (vec![0x80, 0xD1], vec![]),
+            ]
+        );
+        done(max);
+    }
+
+    #[test]
+    fn truncated_transfer() {
+        let ncs = PinMock::new(&[
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+        ]);
+        let mut max = Max31865::new(ShortSpi, ncs, PinMock::new(&[])).unwrap();
+
+        assert!(matches!(
+            max.read_config_raw(),
+            Err(Error::IncompleteTransfer)
+        ));
+        max.ncs.done();
+    }
+
+    #[test]
+    fn stats() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00, 0x00], vec![0x00, 0x40, 0x01]),
+            ],
+            &[],
+        );
+
+        max.read_raw().unwrap();
+        max.read_raw_bytes().unwrap();
+        assert_eq!(
+            max.stats(),
+            DeviceStats {
+                conversions: 2,
+                faults: 1,
+                spi_errors: 0,
+            }
+        );
+        max.reset_stats();
+        assert_eq!(max.stats(), DeviceStats::default());
+        done(max);
+
+        let ncs = PinMock::new(&[
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+        ]);
+        let mut max = Max31865::new(FailingSpi, ncs, PinMock::new(&[])).unwrap();
+
+        assert!(matches!(max.read_raw(), Err(Error::SPIError(()))));
+        assert!(matches!(
+            max.write_config_raw(0xD1),
+            Err(Error::SPIError(()))
+        ));
+        assert_eq!(max.stats().spi_errors, 2);
+        assert_eq!(max.stats().conversions, 0);
+        max.ncs.done();
+    }
+
+    #[test]
+    fn one_shot_low_power() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xD1]),
+                SpiTransaction::write(vec![0x80, 0x91]),
+                SpiTransaction::write(vec![0x80, 0xB1]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::write(vec![0x80, 0x11]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+
+        assert_eq!(
+            max.read_one_shot_low_power(&mut MockNoop::new()).unwrap(),
+            0
+        );
+        done(max);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn format_negative_temperature() {
+        use crate::temp_conversion::TempUnit;
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_temperature_offset(-507);
+        assert_eq!(
+            max.format_temperature(TempUnit::Celsius).unwrap().as_str(),
+            "-5.07°C"
+        );
+        done(max);
+    }
+
+    #[test]
+    fn coarse_conversion() {
+        let mut max = driver(
+            &[SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40])],
+            &[],
+        );
+
+        // 0x40 is 100 Ohm, the assumed midpoint of the step is 100.78 Ohm.
+        let temp = max.read_coarse_conversion().unwrap();
+        assert!((190..=210).contains(&temp), "{}", temp);
+        done(max);
+    }
+
+    #[test]
+    fn read_code_signed() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xA0]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x02]),
+            ],
+            &[],
+        );
+
+        // 100 Ohm, a quarter of the reference
+        assert_eq!(max.read_code_signed().unwrap(), 0x2000);
+        // 250 Ohm, above half of the reference, e.g. a PT100 at 408 C°
+        assert_eq!(max.read_code_signed().unwrap(), -0x3000 + 1);
+        done(max);
+    }
+
+    #[test]
+    fn read_raw_validated() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        assert!(matches!(
+            max.read_raw_validated(),
+            Err(Error::SuspiciousReading)
+        ));
+        assert!(matches!(
+            max.read_raw_validated(),
+            Err(Error::SuspiciousReading)
+        ));
+        assert_eq!(max.read_raw_validated().unwrap(), 0x4000);
+        done(max);
+    }
+
+    #[test]
+    fn read_default_conversion_as() {
+        use super::RtdCurve;
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x80]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x80]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        // 200 Ohm is about 266 C° for a PT100 and -196 C° for a PT1000
+        let pt100 = max.read_default_conversion_as(Rtd::Pt100).unwrap();
+        assert_eq!(pt100, Pt100.lookup_table().lookup_temperature(20_000));
+        assert!((26_600..26_700).contains(&pt100), "{}", pt100);
+
+        let pt1000 = max.read_default_conversion_as(Rtd::Pt1000).unwrap();
+        assert_eq!(pt1000, Pt1000.lookup_table().lookup_temperature(20_000));
+        assert!((-19_700..-19_600).contains(&pt1000), "{}", pt1000);
+        done(max);
+    }
+
+    #[test]
+    fn read_temperature_in() {
+        use crate::temp_conversion::TempUnit;
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        // 0 C° plus the offset is 100 C°
+        max.set_temperature_offset(10_000);
+        assert_eq!(
+            max.read_temperature_in(TempUnit::Fahrenheit).unwrap(),
+            21_200
+        );
+        assert_eq!(max.read_temperature_in(TempUnit::Kelvin).unwrap(), 37_315);
+        done(max);
+    }
+
+    #[test]
+    fn read_temperature_bcd() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_temperature_offset(-1_234);
+        assert_eq!(max.read_temperature_bcd().unwrap(), 0xF000_1234);
+        max.set_temperature_offset(85_001);
+        assert_eq!(max.read_temperature_bcd().unwrap(), 0x0008_5001);
+        done(max);
+    }
+
+    #[test]
+    fn correction_order() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        // 100 Ohm measured, 99 Ohm after the leads, which the two-point
+        // calibration maps to exactly 100 Ohm (0 C°). Applying the two-point
+        // calibration before the lead resistance would give 100.02 Ohm.
+        max.set_lead_resistance(100);
+        max.set_two_point_calibration((9900, 10000), (19800, 20100))
+            .unwrap();
+        max.set_temperature_offset(50);
+
+        assert_eq!(max.read_default_conversion().unwrap(), 50);
+        done(max);
+    }
+
+    #[test]
+    fn two_point_calibration_rejects_equal_points() {
+        let mut max = driver(&[], &[]);
+
+        assert!(matches!(
+            max.set_two_point_calibration((10000, 10000), (10000, 20000)),
+            Err(Error::InvalidCalibration)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn two_point_calibration_extreme_gain() {
+        use super::{RtdCurve, MAX_CALIBRATION};
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x50]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        assert!(matches!(
+            max.set_two_point_calibration((10_000, 10_000), (10_001, 4_000_000)),
+            Err(Error::InvalidCalibration)
+        ));
+
+        // 125 Ohm is corrected far beyond the range of the lookup table and
+        // limited to MAX_CALIBRATION rather than overflowing the lookup
+        max.set_two_point_calibration((10_000, 10_000), (10_001, MAX_CALIBRATION))
+            .unwrap();
+        let max_temp = Pt100
+            .lookup_table()
+            .lookup_temperature(MAX_CALIBRATION as i32);
+        assert_eq!(max.read_default_conversion().unwrap(), max_temp);
+        done(max);
+    }
+
+    #[test]
+    fn detect_rtd_type() {
+        // (msb, lsb, expected), with a calibration of 32768 the code equals
+        // the resistance in ohms multiplied by 100
+        let cases = [
+            (0x40, 0x00, Some(Rtd::Pt100)),
+            (0x3E, 0x80, Some(Rtd::Pt100)),
+            (0x3E, 0x7E, None),
+            (0x7D, 0x00, Some(Rtd::Pt100)),
+            (0x7D, 0x02, None),
+            (0xFF, 0xFE, None),
+        ];
+
+        let mut spi = Vec::new();
+        for (msb, lsb, _) in cases {
+            spi.push(SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, msb]));
+            spi.push(SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, lsb]));
+        }
+        let rdy = vec![PinTransaction::get(State::Low); cases.len()];
+        let mut max = driver(&spi, &rdy);
+
+        max.set_calibration(32768);
+        for (msb, lsb, expected) in cases {
+            let rtd = max.detect_rtd_type(&mut MockNoop::new()).unwrap();
+            assert_eq!(rtd, expected, "{:02X}{:02X}", msb, lsb);
+        }
+        done(max);
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x3B]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x8A]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFE]),
+            ],
+            &[
+                PinTransaction::get(State::Low),
+                PinTransaction::get(State::Low),
+            ],
+        );
+
+        // 1000.06 Ohm with a 4.3 kOhm reference
+        max.set_reference_resistor(430_000);
+        assert_eq!(
+            max.detect_rtd_type(&mut MockNoop::new()).unwrap(),
+            Some(Rtd::Pt1000)
+        );
+        // a saturated reading of almost 4.3 kOhm matches neither
+        assert_eq!(max.detect_rtd_type(&mut MockNoop::new()).unwrap(), None);
+        done(max);
+    }
+
+    #[test]
+    fn cached_reading() {
+        use super::RtdCurve;
+
+        let reads = [
+            SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+            SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+            SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xA4]),
+        ];
+        // 101 Ohm
+        let warmer = Pt100.lookup_table().lookup_temperature(10_100);
+
+        for start in [100, u32::MAX - 5] {
+            let mut max = driver(&reads, &[]);
+            let mut ticks = [0, 10, 11, 21].iter().map(|t| start.wrapping_add(*t));
+            let mut clock = || ticks.next().unwrap();
+
+            assert_eq!(max.cached_reading(10, &mut clock).unwrap(), 0);
+            // at most max_age old, no SPI traffic
+            assert_eq!(max.cached_reading(10, &mut clock).unwrap(), 0);
+            assert_eq!(max.cached_reading(10, &mut clock).unwrap(), warmer);
+            assert_eq!(max.cached_reading(10, &mut clock).unwrap(), warmer);
+            done(max);
+        }
+    }
+
+    #[test]
+    fn read_into() {
+        use super::READING_LEN;
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+            ],
+            &[],
+        );
+        let mut buf = [0xAA; READING_LEN + 1];
+
+        assert!(matches!(
+            max.read_into(&mut buf[..READING_LEN - 1]),
+            Err(Error::BufferTooSmall)
+        ));
+
+        // 23.45 C° rounds to 23.5 C°
+        max.set_temperature_offset(2_345);
+        assert_eq!(max.read_into(&mut buf).unwrap(), READING_LEN);
+        assert_eq!(buf, [0x00, 0xEB, 0x00, 0xAA]);
+
+        // -23.44 C° rounds to -23.4 C°
+        max.set_temperature_offset(-2_344);
+        assert_eq!(max.read_into(&mut buf).unwrap(), READING_LEN);
+        assert_eq!(buf, [0xFF, 0x16, 0x00, 0xAA]);
+
+        // the fault bit is reported in the status byte
+        assert_eq!(max.read_into(&mut buf).unwrap(), READING_LEN);
+        assert_eq!(buf, [0xFF, 0x16, 0x01, 0xAA]);
+        done(max);
+    }
+
+    #[test]
+    fn read_checked_returns_fault_status() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x07, 0x00], vec![0x00, 0x80]),
+            ],
+            &[],
+        );
+
+        let (temp, status) = max.read_checked().unwrap();
+        assert_eq!(temp, 0);
+        assert!(status.high_threshold());
+        done(max);
+    }
+
+    #[test]
+    fn resistance_at() {
+        assert_eq!(Rtd::Pt100.resistance_at(0), 10_000);
+        assert_eq!(Rtd::Pt100.resistance_at(2_500), 10_972);
+        assert_eq!(Rtd::Pt100.resistance_at(10_000), 13_851);
+        assert_eq!(Rtd::Pt1000.resistance_at(10_000), 138_505);
+    }
+
+    #[test]
+    fn convert_block() {
+        let mut out = [0; 3];
+        super::convert_block(
+            &[0x4000, 0x4001, 0x58A4],
+            &mut out,
+            40_000,
+            RefWiring::Standard,
+            Rtd::Pt100,
+        );
+
+        assert_eq!(out[0], 0);
+        assert_eq!(out[1], super::FAULTED_SAMPLE);
+        assert!((9_990..=10_010).contains(&out[2]), "{}", out[2]);
+
+        super::convert_block(
+            &[0xB8D2, 0x0000, 0x0002],
+            &mut out,
+            10_000,
+            RefWiring::Swapped,
+            Rtd::Pt100,
+        );
+        assert!((9_990..=10_010).contains(&out[0]), "{}", out[0]);
+        assert_eq!(out[1], 86_000);
+        assert_eq!(out[2], 86_000);
+    }
+
+    #[test]
+    fn tolerance_band() {
+        assert_eq!(Rtd::Pt100.tolerance_band(ToleranceClass::B, 0), 30);
+        assert_eq!(Rtd::Pt100.tolerance_band(ToleranceClass::B, 10_000), 80);
+        assert_eq!(Rtd::Pt1000.tolerance_band(ToleranceClass::A, -10_000), 35);
+        assert_eq!(ToleranceClass::AA.band(10_000), 27);
+        assert_eq!(ToleranceClass::C.band(85_000), 910);
+    }
+
+    #[test]
+    fn rtd_temperature_range() {
+        assert_eq!(Rtd::Pt100.temperature_range(), (-20_000, 86_000));
+    }
+
+    #[test]
+    fn validate_reference() {
+        let mut max = driver(&[], &[]);
+
+        assert!(max.validate_reference_for(Rtd::Pt100));
+        assert!(!max.validate_reference_for(Rtd::Pt1000));
+        assert!(max.check_reference().is_ok());
+
+        max.set_reference_resistor(430_000);
+        assert!(!max.validate_reference_for(Rtd::Pt100));
+        assert!(max.validate_reference_for(Rtd::Pt1000));
+        assert!(matches!(
+            max.check_reference(),
+            Err(Error::ReferenceMismatch)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn sleep_and_wake() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xD1]),
+                SpiTransaction::write(vec![0x80, 0x11]),
+                SpiTransaction::write(vec![0x80, 0xD1]),
+            ],
+            &[],
+        );
+
+        max.sleep().unwrap();
+        max.sleep().unwrap();
+        max.wake(&mut MockNoop::new()).unwrap();
+        max.wake(&mut MockNoop::new()).unwrap();
+        done(max);
+    }
+
+    #[test]
+    fn swapped_reference_wiring() {
+        let mut max = driver(
+            &[
+                // 100 Ohm reference measured against 138.51 Ohm, code 0x5C69.
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xB8]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xD2]),
+            ],
+            &[],
+        );
+
+        max.set_reference_wiring(RefWiring::Swapped);
+        max.set_reference_resistor(10_000);
+        assert_eq!(max.read_ohms().unwrap(), Ohms(13_851));
+        done(max);
+    }
+
+    #[test]
+    fn pipeline_matches_driver() {
+        use crate::pipeline::{
+            CurveConversion, LeadResistance, Offset, Pipeline, SwappedCodeToOhms,
+            TwoPointCorrection,
+        };
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xB8]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xD2]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+        max.set_reference_wiring(RefWiring::Swapped);
+        max.set_reference_resistor(10_000);
+        max.set_lead_resistance(40);
+        max.set_two_point_calibration((10_000, 10_020), (13_800, 13_851))
+            .unwrap();
+        max.set_temperature_offset(-15);
+
+        let mut ohms = SwappedCodeToOhms {
+            calibration: 10_000,
+            curve: Pt100,
+        };
+        let mut leads = LeadResistance(40);
+        let mut two_point = TwoPointCorrection {
+            low: (10_000, 10_020),
+            high: (13_800, 13_851),
+        };
+        let mut curve = CurveConversion(Pt100);
+        let mut offset = Offset(-15);
+        let mut pipeline = Pipeline::<5>::new();
+        assert!(pipeline.push(&mut ohms).is_ok());
+        assert!(pipeline.push(&mut leads).is_ok());
+        assert!(pipeline.push(&mut two_point).is_ok());
+        assert!(pipeline.push(&mut curve).is_ok());
+        assert!(pipeline.push(&mut offset).is_ok());
+
+        let temp = max.read_default_conversion().unwrap();
+        assert_eq!(pipeline.process(0xB8D2), temp);
+        let temp = max.read_default_conversion().unwrap();
+        assert_eq!(pipeline.process(0x0000), temp);
+        done(max);
+    }
+
+    #[test]
+    fn swapped_reference_wiring_near_zero_code() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x02]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x02]),
+            ],
+            &[],
+        );
+
+        max.set_reference_wiring(RefWiring::Swapped);
+        max.set_reference_resistor(10_000);
+        assert_eq!(max.read_default_conversion().unwrap(), 86_000);
+        assert_eq!(max.read_default_conversion().unwrap(), 86_000);
+        assert_eq!(max.read_temperature_millidegrees().unwrap(), 860_000);
+        done(max);
+    }
+
+    #[test]
+    fn calibration_limit() {
+        use super::{RtdCurve, MAX_CALIBRATION};
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFE]),
+            ],
+            &[],
+        );
+
+        assert!(matches!(
+            max.try_set_calibration(0),
+            Err(Error::CalibrationOutOfRange)
+        ));
+        assert!(matches!(
+            max.try_set_calibration(MAX_CALIBRATION + 1),
+            Err(Error::CalibrationOutOfRange)
+        ));
+        assert_eq!(max.calibration, 40_000);
+
+        max.set_calibration(u32::MAX);
+        assert_eq!(max.calibration, u32::MAX);
+        max.set_reference_resistor(43_000);
+        assert_eq!(max.calibration, 43_000);
+        max.set_reference_resistor(u32::MAX);
+        assert_eq!(max.calibration, u32::MAX);
+
+        // the largest code with the largest calibration is limited to
+        // MAX_CALIBRATION instead of overflowing the lookup
+        let limit = Pt100
+            .lookup_table()
+            .lookup_temperature(MAX_CALIBRATION as i32);
+        assert_eq!(max.read_default_conversion().unwrap(), limit);
+        done(max);
+    }
+
+    #[test]
+    fn calibrate_against_reference_swapped() {
+        let mut max = driver(
+            &[
+                // 100 Ohm measured against a nominal 100 Ohm reference
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFE]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFE]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+        max.set_reference_wiring(RefWiring::Swapped);
+        max.set_reference_resistor(10_000);
+
+        // the reference reads 20 C°, i.e. the RTD is 107.79 Ohm, so the
+        // reference resistor is 107.79 Ohm as well
+        max.calibrate_against_reference(2_000, &mut MockNoop::new())
+            .unwrap();
+        assert_eq!(max.calibration, 10_778);
+        let temp = max.read_default_conversion().unwrap();
+        assert!((1_990..=2_010).contains(&temp), "{}", temp);
+        done(max);
+    }
+
+    #[test]
+    fn read_rate_checked() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                // A spike of 200 Ohm.
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x80]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        assert_eq!(max.read_rate_checked(500).unwrap(), 0);
+        assert!(matches!(
+            max.read_rate_checked(500),
+            Err(Error::RateExceeded)
+        ));
+        assert_eq!(max.read_rate_checked(500).unwrap(), 0);
+        done(max);
+    }
+
+    #[test]
+    fn identify() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(
+                    vec![0x00; 9],
+                    vec![0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00],
+                ),
+                // A floating bus.
+                SpiTransaction::transfer(vec![0x00; 9], vec![0xFF; 9]),
+            ],
+            &[],
+        );
+
+        assert!(max.identify().unwrap());
+        assert!(!max.identify().unwrap());
+        done(max);
+    }
+
+    #[test]
+    fn bias_check() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                // The device was reset, the configuration is cleared.
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_bias_check_interval(2);
+        assert_eq!(max.read_default_conversion().unwrap(), 0);
+        assert!(matches!(
+            max.read_default_conversion(),
+            Err(Error::BiasDisabled)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn read_with_age() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[
+                PinTransaction::get(State::High),
+                PinTransaction::get(State::Low),
+                PinTransaction::get(State::Low),
+            ],
+        );
+        let mut tick = 0;
+        let mut clock = || {
+            tick += 10;
+            tick
+        };
+
+        assert!(!max.track_ready(&mut clock).unwrap());
+        // The clock is only sampled on the transition (tick 10) and the read
+        // (tick 20).
+        assert!(max.track_ready(&mut clock).unwrap());
+        assert_eq!(max.read_with_age(&mut clock).unwrap(), (0, 10));
+        done(max);
+    }
+
+    #[test]
+    fn read_block() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[
+                PinTransaction::get(State::Low),
+                PinTransaction::get(State::High),
+                PinTransaction::get(State::Low),
+            ],
+        );
+
+        let mut buf = [-1; 2];
+        assert_eq!(max.read_block(&mut buf, &mut MockNoop::new()).unwrap(), 2);
+        assert_eq!(buf, [0, 0]);
+        done(max);
+    }
+
+    #[test]
+    fn poll_read() {
+        use core::task::Poll;
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[
+                PinTransaction::get(State::High),
+                PinTransaction::get(State::Low),
+            ],
+        );
+
+        assert_eq!(max.poll_read().unwrap(), Poll::Pending);
+        assert_eq!(max.poll_read().unwrap(), Poll::Ready(0));
+        done(max);
+    }
+
+    #[test]
+    fn reference_health_check() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+
+        let deviation = max
+            .reference_health_check(2_000, &mut MockNoop::new())
+            .unwrap();
+        assert_eq!(deviation, -2_000);
+        done(max);
+    }
+
+    #[test]
+    fn with_oneshot() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC1]),
+                SpiTransaction::write(vec![0x80, 0x81]),
+                SpiTransaction::write(vec![0x80, 0xA1]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+
+        let doubled = max
+            .with_oneshot(&mut MockNoop::new(), |temp| temp * 2)
+            .unwrap();
+        assert_eq!(doubled, 0);
+        assert_eq!(max.config, 0xC1);
+        done(max);
+    }
+
+    #[test]
+    fn dyn_curve() {
+        let curves: [DynCurve; 3] = [&Pt100, &Pt1000, &Rtd::Pt1000];
+        for curve in curves {
+            let r0 = curve.nominal_resistance() as i32;
+            assert_eq!(curve.resistance_to_temperature(r0), 0);
+        }
+
+        let spi = [
+            SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+            SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+        ];
+        let mut ncs = vec![PinTransaction::set(State::High)];
+        for _ in &spi {
+            ncs.push(PinTransaction::set(State::Low));
+            ncs.push(PinTransaction::set(State::High));
+        }
+        let mut max = Max31865::new_with_curve(
+            SpiMock::new(&spi),
+            PinMock::new(&ncs),
+            PinMock::new(&[]),
+            curves[1],
+        )
+        .unwrap();
+        max.config = CONFIG_VBIAS;
+        max.set_reference_resistor(400_000);
+
+        assert_eq!(max.read_default_conversion().unwrap(), 0);
+        max.spi.done();
+        max.ncs.done();
+        max.rdy.done();
+    }
+
+    #[test]
+    fn with_fault_detection_disabled() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xEB]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                // the one-shot and fault clear bits are not written back
+                SpiTransaction::write(vec![0x80, 0xC9]),
+            ],
+            &[],
+        );
+
+        let temp = max
+            .with_fault_detection_disabled(|max| {
+                assert_eq!(max.config, 0xC1);
+                max.read_default_conversion()
+            })
+            .unwrap();
+        assert_eq!(temp, 0);
+        assert_eq!(max.config, 0xC9);
+        done(max);
+    }
+
+    #[test]
+    fn read_raw_no_bias() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC1]),
+                SpiTransaction::write(vec![0x80, 0x01]),
+                SpiTransaction::write(vec![0x80, 0x21]),
+                SpiTransaction::transfer(vec![0x01, 0x00, 0x00], vec![0x00, 0x00, 0x07]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+        max.set_bias_check_interval(1);
+
+        assert_eq!(max.read_raw_no_bias(&mut MockNoop::new()).unwrap(), 0x0007);
+        assert_eq!(max.config, 0xC1);
+        done(max);
+    }
+
+    #[test]
+    fn init() {
+        let mut max = driver(
+            &[
+                SpiTransaction::write(vec![0x80, 0xC1]),
+                // Both thresholds in one transaction, 100 C° and 0 C°.
+                SpiTransaction::write(vec![0x83, 0x58, 0xA4, 0x40, 0x00]),
+            ],
+            &[],
+        );
+
+        let config = Config::new()
+            .vbias(true)
+            .conversion_mode(true)
+            .filter_50hz();
+        max.init(config, 0, 10_000).unwrap();
+        done(max);
+    }
+
+    #[test]
+    fn profile_settling() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC1]),
+                SpiTransaction::write(vec![0x80, 0x41]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x3F]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        let mut out = [0; 3];
+        max.profile_settling(&mut MockNoop::new(), 2, 5_000, &mut out)
+            .unwrap();
+        assert!(out[0] < 0);
+        assert_eq!(out[1..], [0, 0]);
+        done(max);
+    }
+
+    #[test]
+    fn wait_for_ready() {
+        let mut max = driver(
+            &[],
+            &[
+                PinTransaction::get(State::High),
+                PinTransaction::get(State::Low),
+                PinTransaction::get(State::Low),
+            ],
+        );
+
+        assert!(!max.is_ready().unwrap());
+        assert!(max.is_ready().unwrap());
+        max.wait_for_ready(&mut MockNoop::new(), 10).unwrap();
+        done(max);
+    }
+
+    #[test]
+    fn wait_for_ready_never_ready() {
+        // Polled at 0, 1, 2 and 3 ms.
+        let polls = vec![PinTransaction::get(State::High); 4];
+        let mut max = driver(&[], &polls);
+
+        assert!(matches!(
+            max.wait_for_ready(&mut MockNoop::new(), 3),
+            Err(Error::Timeout)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn wait_for_ready_timeout() {
+        let mut polls = vec![PinTransaction::get(State::High); 2];
+        polls.push(PinTransaction::get(State::Low));
+        polls.extend(vec![PinTransaction::get(State::High); 2]);
+        let mut max = driver(&[], &polls);
+
+        max.wait_for_ready_timeout(&mut MockNoop::new(), 5).unwrap();
+        assert!(matches!(
+            max.wait_for_ready_timeout(&mut MockNoop::new(), 1),
+            Err(Error::Timeout)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn read_blocking_with_feed() {
+        use super::CONVERSION_TIMEOUT_MS;
+
+        let polls = CONVERSION_TIMEOUT_MS as usize + 1;
+        let mut rdy = vec![
+            PinTransaction::get(State::High),
+            PinTransaction::get(State::High),
+            PinTransaction::get(State::Low),
+        ];
+        rdy.extend(vec![PinTransaction::get(State::High); polls]);
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &rdy,
+        );
+        let mut fed = 0;
+
+        let temp = max
+            .read_blocking_with_feed(&mut MockNoop::new(), &mut || fed += 1)
+            .unwrap();
+        assert_eq!(temp, 0);
+        assert_eq!(fed, 3);
+
+        // the ready pin is never asserted, polled at 0 to the timeout in ms
+        fed = 0;
+        assert!(matches!(
+            max.read_blocking_with_feed(&mut MockNoop::new(), &mut || fed += 1),
+            Err(Error::Timeout)
+        ));
+        assert_eq!(fed, polls);
+        done(max);
+    }
+
+    #[test]
+    fn read_robust_average() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                // A spike of 200 Ohm.
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x80]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &vec![PinTransaction::get(State::Low); 4],
+        );
+
+        assert_eq!(max.read_robust_average(4, &mut MockNoop::new()).unwrap(), 0);
+        done(max);
+    }
+
+    #[test]
+    fn decimating_reader() {
+        use super::decimate::DecimatingReader;
+
+        let max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                // One code above 100 Ohm.
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x02]),
+            ],
+            &[
+                PinTransaction::get(State::Low),
+                PinTransaction::get(State::High),
+                PinTransaction::get(State::Low),
+            ],
+        );
+
+        let mut reader: DecimatingReader<_, _, _, _, 2> = DecimatingReader::new(max);
+        assert_eq!(reader.poll().unwrap(), None);
+        assert_eq!(reader.poll().unwrap(), None);
+        // The average of 0 and the 30 millidegrees of one code.
+        assert_eq!(reader.poll().unwrap(), Some(15));
+        done(reader.release());
+    }
+
+    #[test]
+    fn read_and_update_thresholds() {
+        let mut max = driver(
+            &[
+                SpiTransaction::write(vec![0x83, 0x58]),
+                SpiTransaction::write(vec![0x84, 0xA4]),
+                SpiTransaction::write(vec![0x85, 0x40]),
+                SpiTransaction::write(vec![0x86, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+
+        let temp = max
+            .read_and_update_thresholds(0, 10_000, &mut MockNoop::new())
+            .unwrap();
+        assert_eq!(temp, 0);
+        done(max);
+    }
+
+    #[test]
+    fn ready_fallback() {
+        let polls = vec![PinTransaction::get(State::High); 66];
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &polls,
+        );
+
+        max.set_ready_fallback(true);
+        let reading = max.read_blocking(&mut MockNoop::new()).unwrap();
+        assert_eq!(reading.temperature, 0);
+        assert!(reading.unconfirmed);
+        assert!(max.ready_timed_out());
+        done(max);
+    }
+
+    #[test]
+    fn set_thresholds() {
+        let mut max = driver(
+            &[
+                // 100 C° is 138.51 Ohm or code 0x2C52, 0 C° is code 0x2000
+                SpiTransaction::write(vec![0x83, 0x58]),
+                SpiTransaction::write(vec![0x84, 0xA4]),
+                SpiTransaction::write(vec![0x85, 0x40]),
+                SpiTransaction::write(vec![0x86, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+                SpiTransaction::transfer(vec![0x07, 0x00], vec![0x00, 0x40]),
+            ],
+            &[],
+        );
+
+        max.set_thresholds(0, 10_000).unwrap();
+        let result = max.read_and_check_thresholds().unwrap();
+        assert_eq!(result.temperature, 0);
+        assert!(!result.high_tripped);
+        assert!(result.low_tripped);
+        done(max);
+    }
+
+    #[test]
+    fn set_thresholds_zero_calibration() {
+        let mut max = driver(
+            &[
+                SpiTransaction::write(vec![0x83, 0xFF]),
+                SpiTransaction::write(vec![0x84, 0xFE]),
+                SpiTransaction::write(vec![0x85, 0xFF]),
+                SpiTransaction::write(vec![0x86, 0xFE]),
+            ],
+            &[],
+        );
+
+        max.set_calibration(0);
+        max.set_thresholds(0, 10_000).unwrap();
+        done(max);
+    }
+
+    #[test]
+    fn bus_speed_suspect() {
+        let mut transactions =
+            vec![SpiTransaction::transfer(vec![0x03, 0, 0], vec![0, 0xFF, 0xFF]); 3];
+        transactions.push(SpiTransaction::transfer(
+            vec![0x03, 0, 0],
+            vec![0, 0xFF, 0x7F],
+        ));
+        let mut max = driver(&transactions, &[]);
+
+        assert!(matches!(max.check_bus_speed(), Err(Error::BusSpeedSuspect)));
+        done(max);
+    }
+
+    #[test]
+    fn read_with_retries() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        assert!(matches!(
+            max.read_with_retries(1, &mut MockNoop::new()),
+            Err(Error::ConversionFault)
+        ));
+        assert_eq!(max.read_with_retries(0, &mut MockNoop::new()).unwrap(), 0);
+        done(max);
+    }
+
+    #[test]
+    fn read_with_default() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x01]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        assert_eq!(max.read_with_default(15_000).unwrap(), 15_000);
+        // an open RTD reads as full scale with the fault bit set
+        assert_eq!(max.read_with_default(15_000).unwrap(), 15_000);
+        assert_eq!(max.read_with_default(15_000).unwrap(), 0);
+        done(max);
+    }
+
+    #[test]
+    fn read_with_default_bus_error() {
+        let ncs = PinMock::new(&[
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+        ]);
+        let mut max = Max31865::new(FailingSpi, ncs, PinMock::new(&[])).unwrap();
+
+        assert!(matches!(
+            max.read_with_default(15_000),
+            Err(Error::SPIError(()))
+        ));
+        max.ncs.done();
+    }
+
+    #[test]
+    fn read_milliohms() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x58]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xA6]),
+            ],
+            &[],
+        );
+
+        max.set_lead_resistance(100);
+        // code 0x2C53 is 138.5131 Ohm, minus 1 Ohm of leads
+        assert_eq!(max.read_milliohms().unwrap(), 137_513);
+        done(max);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "configure() must be called")]
+    fn read_before_configure() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+        max.config = 0;
+
+        let _ = max.read_default_conversion();
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "configure() must be called")]
+    fn read_into_before_configure() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+        max.config = 0;
+
+        let _ = max.read_into(&mut [0; 3]);
+    }
+
+    #[test]
+    fn poll_any_reads_first_ready() {
+        let idle = driver(&[], &[PinTransaction::get(State::High)]);
+        let ready = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+        let unpolled = driver(&[], &[]);
+
+        let mut devices = [idle, ready, unpolled];
+        assert_eq!(poll_any(&mut devices).unwrap(), Some((1, 0)));
+
+        for device in devices {
+            done(device);
+        }
+    }
+
+    #[test]
+    fn calibrated_range() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        assert_eq!(max.calibrated_range(), (-20_000, 86_000));
+        assert_eq!(max.read_range_checked().unwrap(), (0, true));
+
+        max.set_calibrated_range(10_000, 2_000);
+        assert_eq!(max.read_range_checked().unwrap(), (0, false));
+        done(max);
+    }
+
+    #[test]
+    fn read_sensor_range_checked() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                // an open RTD, full scale with the fault bit set
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0xFF]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0xFF]),
+                // a shorted RTD
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_temperature_offset(50);
+        assert_eq!(max.read_sensor_range_checked().unwrap(), 50);
+        assert!(matches!(
+            max.read_sensor_range_checked(),
+            Err(Error::OutOfSensorRange)
+        ));
+        assert!(matches!(
+            max.read_sensor_range_checked(),
+            Err(Error::OutOfSensorRange)
+        ));
+        done(max);
+    }
+
+    #[test]
+    fn detect_mains_interference() {
+        let filter = |filter: u8| {
+            vec![
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC0 | (filter ^ 1)]),
+                SpiTransaction::write(vec![0x80, 0x80 | (filter ^ 1)]),
+                SpiTransaction::write(vec![0x80, 0x80 | filter]),
+                SpiTransaction::write(vec![0x80, 0xC0 | filter]),
+            ]
+        };
+        let sample = |msb: u8| {
+            vec![
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, msb]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ]
+        };
+
+        let spi = [
+            filter(1),
+            sample(0x40),
+            sample(0x40),
+            filter(0),
+            sample(0x40),
+            sample(0x41),
+            filter(1),
+        ]
+        .concat();
+        let mut max = driver(&spi, &vec![PinTransaction::get(State::Low); 4]);
+        max.config |= CONFIG_FILTER_MODE;
+
+        let estimate = max
+            .detect_mains_interference(&mut MockNoop::new(), 2)
+            .unwrap();
+        assert_eq!(estimate.ripple_50hz, 0);
+        assert_eq!(estimate.ripple_60hz, 128);
+        assert_eq!(estimate.recommended, Some(FilterMode::Filter50Hz));
+        assert_eq!(estimate.settled, FilterMode::Filter50Hz);
+        assert!(!estimate.switched);
+        done(max);
+    }
+
+    #[test]
+    fn detect_mains_interference_auto_switch() {
+        let filter = |filter: u8| {
+            vec![
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC0 | (filter ^ 1)]),
+                SpiTransaction::write(vec![0x80, 0x80 | (filter ^ 1)]),
+                SpiTransaction::write(vec![0x80, 0x80 | filter]),
+                SpiTransaction::write(vec![0x80, 0xC0 | filter]),
+            ]
+        };
+        let sample = |msb: u8| {
+            vec![
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, msb]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ]
+        };
+
+        let spi = [
+            filter(1),
+            sample(0x40),
+            sample(0x40),
+            filter(0),
+            sample(0x40),
+            sample(0x41),
+            filter(0),
+            filter(1),
+        ]
+        .concat();
+        let mut max = driver(&spi, &vec![PinTransaction::get(State::Low); 4]);
+        max.set_filter_auto_switch(true);
+
+        let estimate = max
+            .detect_mains_interference(&mut MockNoop::new(), 2)
+            .unwrap();
+        assert_eq!(estimate.recommended, Some(FilterMode::Filter50Hz));
+        assert_eq!(estimate.settled, FilterMode::Filter50Hz);
+        assert!(estimate.switched);
+        assert_eq!(max.config & CONFIG_FILTER_MODE, CONFIG_FILTER_MODE);
+        done(max);
+    }
+
+    #[test]
+    fn read_temperature_full() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_temperature_offset(-507);
+        assert_eq!(max.read_temperature_full().unwrap(), (-507, -5, 7));
+        max.set_temperature_offset(-50);
+        assert_eq!(max.read_temperature_full().unwrap(), (-50, 0, 50));
+        done(max);
+    }
+
+    #[test]
+    fn read_raw_bytes() {
+        let mut max = driver(
+            &[SpiTransaction::transfer(
+                vec![0x01, 0x00, 0x00],
+                vec![0x00, 0x40, 0x01],
+            )],
+            &[],
+        );
+
+        assert_eq!(max.read_raw_bytes().unwrap(), [0x40, 0x01]);
+        done(max);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn read_temperature_fixed() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_temperature_offset(-250);
+        assert_eq!(max.read_temperature_fixed().unwrap(), -2.5);
+        done(max);
+    }
+
+    #[test]
+    fn read_latest() {
+        let mut max = driver(
+            &[
+                SpiTransaction::write(vec![0x80, 0xC1]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.configure(
+            true,
+            true,
+            false,
+            SensorType::TwoOrFourWire,
+            FilterMode::Filter50Hz,
+        )
+        .unwrap();
+        assert_eq!(max.read_latest().unwrap(), 0);
+        done(max);
+    }
+
+    #[test]
+    fn read_modify_config() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xD0]),
+                SpiTransaction::write(vec![0x80, 0xD1]),
+            ],
+            &[],
+        );
+
+        let config = max.read_config().unwrap();
+        assert_eq!(
+            config,
+            Config::new()
+                .vbias(true)
+                .conversion_mode(true)
+                .sensor_type(SensorType::ThreeWire)
+        );
+        max.apply_config(config.filter_50hz()).unwrap();
+        assert_eq!(max.wire_count, WireCount::Three);
+        done(max);
+    }
+
+    #[test]
+    fn read_temperature_millidegrees() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x02]),
+            ],
+            &[],
+        );
+
+        // code 0x2001 is 100.012 Ohm
+        assert_eq!(max.read_temperature_millidegrees().unwrap(), 30);
+        done(max);
+    }
+
+    #[test]
+    fn calibrate_against_reference() {
+        let mut max = driver(
+            &[
+                // 100 Ohm with a nominal 400 Ohm reference
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[PinTransaction::get(State::Low)],
+        );
+
+        // the reference reads 20 C°, i.e. the RTD is 107.79 Ohm
+        max.calibrate_against_reference(2_000, &mut MockNoop::new())
+            .unwrap();
+        assert_eq!(max.calibration, 43_116);
+        assert_eq!(max.read_default_conversion().unwrap(), 2_000);
+        done(max);
+    }
+
+    #[test]
+    fn read_default_conversion_rounded() {
+        use crate::temp_conversion::RoundingMode;
+
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x10]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x10]),
+            ],
+            &[],
+        );
+
+        // code 0x2008 is 0.249 C°
+        assert_eq!(
+            max.read_default_conversion_rounded(RoundingMode::Truncate)
+                .unwrap(),
+            24
+        );
+        assert_eq!(
+            max.read_default_conversion_rounded(RoundingMode::default())
+                .unwrap(),
+            25
+        );
+        done(max);
+    }
+
+    #[test]
+    fn configure_returning_previous() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xC1]),
+                SpiTransaction::write(vec![0x80, 0xA1]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+            ],
+            &[],
+        );
+
+        let previous = max
+            .configure_returning_previous(
+                true,
+                false,
+                true,
+                SensorType::TwoOrFourWire,
+                FilterMode::Filter50Hz,
+            )
+            .unwrap();
+        assert_eq!(previous, 0xC1);
+        max.write_config_raw(previous).unwrap();
+        done(max);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn float_reference_resistor() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_reference_resistor_f32(430.0);
+        assert_eq!(max.reference_resistor(), 43_000);
+        assert_eq!(max.read_ohms_f32().unwrap(), 107.5);
+        done(max);
+    }
+
+    #[test]
+    fn accumulate() {
+        let mut max = driver(
+            &[
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+            ],
+            &[],
+        );
+
+        max.set_average_shift(1);
+        assert_eq!(max.current_average(), None);
+        max.accumulate().unwrap();
+        assert_eq!(max.current_average(), Some(0));
+        max.set_temperature_offset(1_000);
+        max.accumulate().unwrap();
+        assert_eq!(max.current_average(), Some(500));
+        done(max);
+    }
+
+    #[test]
+    fn warm_up() {
+        let reading = |lsb: u8| {
+            vec![
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, lsb]),
+            ]
+        };
+        let spi = [
+            vec![
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0x41]),
+                SpiTransaction::write(vec![0x80, 0xC1]),
+            ],
+            reading(0x00),
+            reading(0x10),
+            reading(0x12),
+        ]
+        .concat();
+        let mut max = driver(&spi, &[]);
+
+        // 0.23 C° of drift, then 0.02 C°
+        assert!(max.warm_up(&mut MockNoop::new(), 1_000).unwrap());
+        done(max);
+    }
+}
@@ -0,0 +1,290 @@
+//! Composable post-processing of readings
+
+use crate::temp_conversion::TempUnit;
+use crate::{conversion, RtdCurve};
+
+/// The input of a `ReadingProcessor` stage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RawReading {
+    /// The raw RTD register value as returned by `Max31865::read_raw`,
+    /// including the fault bit.
+    pub raw: u16,
+    /// The value produced by the previous stages, `0` for the first stage.
+    pub value: i32,
+}
+
+/// The output of a `ReadingProcessor` stage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProcessedReading {
+    /// The value passed on to the next stage. Its meaning depends on the
+    /// stages, e.g. a resistance in Ohms multiplied by 100 after
+    /// `CodeToOhms` and a temperature in degrees Celsius multiplied by 100
+    /// after `CurveConversion`.
+    pub value: i32,
+}
+
+/// A stage of a `Pipeline`.
+pub trait ReadingProcessor {
+    /// Process the value produced by the previous stages.
+    fn process(&mut self, raw: RawReading) -> ProcessedReading;
+}
+
+/// A chain of up to `N` processors applied in order, see
+/// `Max31865::read_processed`.
+///
+/// The processors are borrowed, so stateful stages such as `Smoothing` can be
+/// inspected or reset between readings.
+///
+/// The steps of the driver's correction pipeline are available as stages:
+/// `CodeToOhms` or `SwappedCodeToOhms` depending on the reference wiring,
+/// `LeadResistance` for two wire sensors, `TwoPointCorrection`,
+/// `CurveConversion` and `Offset`. Composed in this order with the driver's
+/// settings they produce the same temperature as
+/// `Max31865::read_default_conversion`. The calibrated range check of
+/// `Max31865::read_range_checked` is not covered, as it flags a reading
+/// rather than changing its value.
+pub struct Pipeline<'a, const N: usize> {
+    stages: [Option<&'a mut dyn ReadingProcessor>; N],
+    len: usize,
+}
+
+impl<'a, const N: usize> Pipeline<'a, N> {
+    /// Create an empty pipeline.
+    pub fn new() -> Self {
+        Pipeline {
+            stages: core::array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    /// Append a processor to the end of the pipeline.
+    ///
+    /// # Remarks
+    ///
+    /// Returns the processor back as an error if the pipeline is full.
+    pub fn push(
+        &mut self,
+        processor: &'a mut dyn ReadingProcessor,
+    ) -> Result<(), &'a mut dyn ReadingProcessor> {
+        if self.len == N {
+            return Err(processor);
+        }
+
+        self.stages[self.len] = Some(processor);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Run a raw RTD register value through all processors in order and
+    /// return the value produced by the last one.
+    pub fn process(&mut self, raw: u16) -> i32 {
+        self.stages[..self.len]
+            .iter_mut()
+            .flatten()
+            .fold(0, |value, stage| {
+                stage.process(RawReading { raw, value }).value
+            })
+    }
+}
+
+impl<const N: usize> Default for Pipeline<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Converts the raw value to a resistance in Ohms multiplied by 100 with the
+/// standard reference wiring, see `Max31865::set_calibration`.
+pub struct CodeToOhms {
+    /// The calibrated reference resistance in Ohms multiplied by 100.
+    pub calibration: u32,
+}
+
+impl ReadingProcessor for CodeToOhms {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        let value = conversion::code_to_ohms100(raw.raw >> 1, self.calibration) as i32;
+
+        ProcessedReading { value }
+    }
+}
+
+/// Converts the raw value to a resistance in Ohms multiplied by 100 with the
+/// reference resistor and the RTD swapped, see `RefWiring::Swapped`.
+///
+/// Like the driver, resistances beyond the lookup table of `curve` are clamped
+/// to its last entry.
+pub struct SwappedCodeToOhms<C> {
+    /// The calibrated reference resistance in Ohms multiplied by 100.
+    pub calibration: u32,
+    /// The RTD curve whose lookup table bounds the resistance.
+    pub curve: C,
+}
+
+impl<C: RtdCurve> ReadingProcessor for SwappedCodeToOhms<C> {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        let max_ohms = self.curve.lookup_table().resistance_range().1.max(0) as u32;
+        let value =
+            conversion::swapped_code_to_ohms100(raw.raw >> 1, self.calibration, max_ohms) as i32;
+
+        ProcessedReading { value }
+    }
+}
+
+/// Applies a two-point correction to a resistance in Ohms multiplied by 100,
+/// see `Max31865::set_two_point_calibration`.
+pub struct TwoPointCorrection {
+    /// The measured and actual resistance of the low calibration point.
+    pub low: (u32, u32),
+    /// The measured and actual resistance of the high calibration point.
+    pub high: (u32, u32),
+}
+
+impl ReadingProcessor for TwoPointCorrection {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        let ohms = conversion::two_point_correction(raw.value as i64, [self.low, self.high], 1);
+
+        ProcessedReading { value: ohms as i32 }
+    }
+}
+
+/// Subtracts the lead resistance in Ohms multiplied by 100 from a resistance,
+/// see `Max31865::set_lead_resistance`.
+pub struct LeadResistance(pub u32);
+
+impl ReadingProcessor for LeadResistance {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        let value = (raw.value - self.0 as i32).max(0);
+
+        ProcessedReading { value }
+    }
+}
+
+/// Converts a resistance to a temperature in degrees Celsius multiplied by
+/// 100 using an RTD curve.
+pub struct CurveConversion<C>(pub C);
+
+impl<C: RtdCurve> ReadingProcessor for CurveConversion<C> {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        let value = self.0.lookup_table().lookup_temperature(raw.value);
+
+        ProcessedReading { value }
+    }
+}
+
+/// Adds a constant offset, see `Max31865::set_temperature_offset`.
+pub struct Offset(pub i32);
+
+impl ReadingProcessor for Offset {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        ProcessedReading {
+            value: raw.value + self.0,
+        }
+    }
+}
+
+/// Converts a temperature in degrees Celsius multiplied by 100 to another
+/// unit, see `TempUnit::from_celsius`.
+pub struct UnitConversion(pub TempUnit);
+
+impl ReadingProcessor for UnitConversion {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        ProcessedReading {
+            value: self.0.from_celsius(raw.value),
+        }
+    }
+}
+
+/// Exponential smoothing of the value.
+///
+/// Each value moves the output by `1 / 2^shift` of its difference to the
+/// previous output. The first value is passed through unchanged.
+pub struct Smoothing {
+    shift: u8,
+    state: Option<i32>,
+}
+
+impl Smoothing {
+    /// Create a smoothing stage, `shift` is clamped to `0..=16`.
+    pub fn new(shift: u8) -> Self {
+        Smoothing {
+            shift: shift.min(16),
+            state: None,
+        }
+    }
+
+    /// Forget the previous output, so that the next value is passed through.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// The last output, or `None` if no value has been processed since the
+    /// stage was created or reset.
+    pub fn value(&self) -> Option<i32> {
+        self.state
+    }
+}
+
+impl ReadingProcessor for Smoothing {
+    fn process(&mut self, raw: RawReading) -> ProcessedReading {
+        let value = match self.state {
+            Some(state) => state + ((raw.value - state) >> self.shift),
+            None => raw.value,
+        };
+        self.state = Some(value);
+
+        ProcessedReading { value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        CodeToOhms, CurveConversion, LeadResistance, Offset, Pipeline, Smoothing,
+        TwoPointCorrection,
+    };
+    use crate::{Pt100, RtdCurve, MAX_CALIBRATION};
+
+    #[test]
+    fn stages_in_order() {
+        let mut ohms = CodeToOhms { calibration: 40000 };
+        let mut leads = LeadResistance(100);
+        let mut curve = CurveConversion(Pt100);
+        let mut offset = Offset(25);
+        let mut smoothing = Smoothing::new(1);
+
+        let mut pipeline = Pipeline::<5>::new();
+        assert!(pipeline.push(&mut ohms).is_ok());
+        assert!(pipeline.push(&mut leads).is_ok());
+        assert!(pipeline.push(&mut curve).is_ok());
+        assert!(pipeline.push(&mut offset).is_ok());
+        assert!(pipeline.push(&mut smoothing).is_ok());
+
+        // 101 Ohm minus 1 Ohm leads is 0 C°
+        assert_eq!(pipeline.process(0x40A4), 25);
+        // 100 Ohm minus 1 Ohm leads is -2.56 C°, smoothed halfway
+        assert_eq!(pipeline.process(0x4000), -103);
+
+        let mut extra = Offset(0);
+        assert!(pipeline.push(&mut extra).is_err());
+    }
+
+    #[test]
+    fn two_point_extreme_gain() {
+        let mut ohms = CodeToOhms { calibration: 40000 };
+        let mut two_point = TwoPointCorrection {
+            low: (0, 0),
+            high: (1, u32::MAX),
+        };
+        let mut curve = CurveConversion(Pt100);
+
+        let mut pipeline = Pipeline::<3>::new();
+        assert!(pipeline.push(&mut ohms).is_ok());
+        assert!(pipeline.push(&mut two_point).is_ok());
+        assert!(pipeline.push(&mut curve).is_ok());
+
+        let max = Pt100
+            .lookup_table()
+            .lookup_temperature(MAX_CALIBRATION as i32);
+        assert_eq!(pipeline.process(0x7FFF), max);
+    }
+}
@@ -0,0 +1,192 @@
+//! Conversions synchronized to a periodic tick source
+
+use crate::hal::blocking::delay::DelayUs;
+use crate::hal::blocking::spi;
+use crate::hal::digital::v2::{InputPin, OutputPin};
+use crate::{Clock, Error, Max31865, RtdCurve};
+
+/// Wraps the driver together with a tick source so that one-shot conversions
+/// are only triggered once per period, producing evenly spaced samples.
+///
+/// The MAX31865 has no hardware synchronization input, so this emulates it:
+/// `poll` is expected to be called frequently, e.g. from the main loop, and
+/// only performs a conversion when a new period boundary has been crossed.
+pub struct SyncedReader<SPI, NCS, RDY, C, K> {
+    max31865: Max31865<SPI, NCS, RDY, C>,
+    clock: K,
+    period: u32,
+    next: Option<u32>,
+}
+
+impl<E, SPI, NCS, RDY, C, K> SyncedReader<SPI, NCS, RDY, C, K>
+where
+    SPI: spi::Write<u8, Error = E> + spi::Transfer<u8, Error = E>,
+    NCS: OutputPin,
+    RDY: InputPin,
+    C: RtdCurve,
+    K: Clock,
+{
+    /// Create a new synchronized reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `max31865` - The configured driver to read the temperature from.
+    /// * `clock` - The tick source defining the period boundaries.
+    /// * `period` - The sampling period in ticks of `clock`. A period of `0`
+    ///              is treated as `1`.
+    pub fn new(max31865: Max31865<SPI, NCS, RDY, C>, clock: K, period: u32) -> Self {
+        SyncedReader {
+            max31865,
+            clock,
+            period: period.max(1),
+            next: None,
+        }
+    }
+
+    /// Take a reading if a new period boundary has been crossed.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - A delay provider used to wait for the conversion.
+    ///
+    /// # Remarks
+    ///
+    /// The output value is the value in degrees Celsius multiplied by 100, or
+    /// `None` if the current period has already been sampled.
+    ///
+    /// The first call always samples and starts the first period. Samples are
+    /// taken with `read_one_shot_low_power`. The period boundaries don't
+    /// drift with the time taken by the conversion; if one or more periods
+    /// were missed entirely they are skipped rather than sampled late in
+    /// quick succession.
+    pub fn poll(&mut self, delay: &mut impl DelayUs<u32>) -> Result<Option<i32>, Error<E>> {
+        let now = self.clock.now();
+
+        if let Some(next) = self.next {
+            if (now.wrapping_sub(next) as i32) < 0 {
+                return Ok(None);
+            }
+        }
+
+        let base = self.next.unwrap_or(now);
+        let elapsed = now.wrapping_sub(base) / self.period;
+        self.next = Some(base.wrapping_add((elapsed + 1).wrapping_mul(self.period)));
+
+        self.max31865.read_one_shot_low_power(delay).map(Some)
+    }
+
+    /// Change the sampling period in ticks. This takes effect after the
+    /// current period has ended.
+    pub fn set_period(&mut self, period: u32) {
+        self.period = period.max(1);
+    }
+
+    /// Access the wrapped driver, e.g. to configure it.
+    pub fn inner_mut(&mut self) -> &mut Max31865<SPI, NCS, RDY, C> {
+        &mut self.max31865
+    }
+
+    /// Release the driver and the tick source.
+    pub fn release(self) -> (Max31865<SPI, NCS, RDY, C>, K) {
+        (self.max31865, self.clock)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SyncedReader;
+    use crate::{Clock, Max31865, CONFIG_VBIAS};
+    use embedded_hal_mock::delay::MockNoop;
+    use embedded_hal_mock::pin::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+    /// Create a synchronized reader with a period of 10 ticks which expects
+    /// `samples` one-shot conversions of 0 C° and reads the given ticks.
+    fn reader(
+        samples: usize,
+        ticks: &'static [u32],
+    ) -> SyncedReader<SpiMock, PinMock, PinMock, crate::Pt100, impl Clock> {
+        let mut spi = Vec::new();
+        let mut rdy = Vec::new();
+        for _ in 0..samples {
+            spi.extend([
+                SpiTransaction::transfer(vec![0x00, 0x00], vec![0x00, 0xD1]),
+                SpiTransaction::write(vec![0x80, 0x91]),
+                SpiTransaction::write(vec![0x80, 0xB1]),
+                SpiTransaction::transfer(vec![0x01, 0x00], vec![0x00, 0x40]),
+                SpiTransaction::transfer(vec![0x02, 0x00], vec![0x00, 0x00]),
+                SpiTransaction::write(vec![0x80, 0x11]),
+            ]);
+            rdy.push(PinTransaction::get(State::Low));
+        }
+
+        let mut ncs = vec![PinTransaction::set(State::High)];
+        for _ in &spi {
+            ncs.push(PinTransaction::set(State::Low));
+            ncs.push(PinTransaction::set(State::High));
+        }
+
+        let mut max =
+            Max31865::new(SpiMock::new(&spi), PinMock::new(&ncs), PinMock::new(&rdy)).unwrap();
+        max.config = CONFIG_VBIAS;
+
+        let mut ticks = ticks.iter();
+        SyncedReader::new(max, move || *ticks.next().unwrap(), 10)
+    }
+
+    fn done<K: Clock>(reader: SyncedReader<SpiMock, PinMock, PinMock, crate::Pt100, K>) {
+        let (max, _) = reader.release();
+        let Max31865 {
+            mut spi,
+            mut ncs,
+            mut rdy,
+            ..
+        } = max;
+        spi.done();
+        ncs.done();
+        rdy.done();
+    }
+
+    #[test]
+    fn samples_once_per_period() {
+        let mut reader = reader(2, &[100, 100, 109, 110, 119]);
+        let mut delay = MockNoop::new();
+
+        // the first call always samples
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        done(reader);
+    }
+
+    #[test]
+    fn skips_missed_periods() {
+        let mut reader = reader(3, &[100, 145, 149, 150]);
+        let mut delay = MockNoop::new();
+
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        // the periods starting at 110, 120 and 130 were missed, the one at
+        // 140 is sampled late and the next boundary stays at 150
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        done(reader);
+    }
+
+    #[test]
+    fn tick_wraparound() {
+        let mut reader = reader(2, &[u32::MAX - 4, u32::MAX, 0, 4, 5, 14]);
+        let mut delay = MockNoop::new();
+
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        // the next boundary wraps around to 5
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        assert_eq!(reader.poll(&mut delay).unwrap(), Some(0));
+        assert_eq!(reader.poll(&mut delay).unwrap(), None);
+        done(reader);
+    }
+}
@@ -9,10 +9,18 @@ pub struct LookupTable<'a, D> {
     data: &'a [D],
 }
 
+/// Linearly interpolate the temperature between two (temperature, resistance)
+/// points. If both points have the same resistance, which can only happen for
+/// a malformed table, the temperature of the first point is returned rather
+/// than dividing by zero.
 fn interpolate(ohm_100: i32, first: (i32, i32), second: (i32, i32)) -> i32 {
     let numerator = (second.0 - first.0) * (ohm_100 - first.1);
     let denominator = second.1 - first.1;
 
+    if denominator == 0 {
+        return first.0;
+    }
+
     numerator / denominator + first.0
 }
 
@@ -51,6 +59,30 @@ where
         (self.min as i32 + (index * self.step as usize) as i32) * 100
     }
 
+    /// The first and last temperature covered by the table.
+    ///
+    /// # Remarks
+    ///
+    /// The temperatures are in degrees Celsius multiplied by 100, e.g.
+    /// `(-20000, 86000)` for `LOOKUP_VEC_PT100`. Conversions outside of this
+    /// range are extrapolated and less accurate.
+    pub fn temperature_range(&self) -> (i32, i32) {
+        (
+            self.reverse_index(0),
+            self.reverse_index(self.data.len() - 1),
+        )
+    }
+
+    /// The first and last resistance of the table.
+    ///
+    /// # Remarks
+    ///
+    /// The resistances are in Ohms multiplied by 100, the resistances at the
+    /// ends of `temperature_range`.
+    pub fn resistance_range(&self) -> (i32, i32) {
+        (self.lookup(0), self.lookup(self.data.len() - 1))
+    }
+
     /// The value from which lower bound interpolation should occur
     fn ohm_lower_bound(&self) -> i32 {
         self.lookup(1)
@@ -61,9 +93,24 @@ where
         self.lookup(self.data.len() - 2)
     }
 
+    /// The index of the first of the two table entries used to interpolate
+    /// the given resistance.
+    fn interval_index(&self, ohm_100: i32) -> usize {
+        if ohm_100 < self.ohm_lower_bound() {
+            0
+        } else if ohm_100 > self.ohm_upper_bound() {
+            self.data.len() - 2
+        } else {
+            match self.binary_search(ohm_100) {
+                Ok(val) => val,
+                Err(val) => val - 1,
+            }
+        }
+    }
+
     fn interpolate_index(&self, ohm_100: i32, index: usize) -> i32 {
-        let first = (self.reverse_index(index) as i32, self.lookup(index));
-        let second = (self.reverse_index(index + 1) as i32, self.lookup(index + 1));
+        let first = (self.reverse_index(index), self.lookup(index));
+        let second = (self.reverse_index(index + 1), self.lookup(index + 1));
         interpolate(ohm_100, first, second)
     }
 
@@ -72,8 +119,8 @@ where
     /// # Arguments
     ///
     /// * `val` - A 16 bit unsigned integer specifying the resistance in Ohms
-    /// multiplied by 100, e.g. 13851 would indicate 138.51 Ohms and convert to 100
-    /// degrees Celsius.
+    ///           multiplied by 100, e.g. 13851 would indicate 138.51 Ohms and
+    ///           convert to 100 degrees Celsius.
     ///
     /// # Remarks
     ///
@@ -83,20 +130,449 @@ where
     /// *Note*: This interpolates from the bottom or top values if the resistance
     /// value is out of range.
     pub fn lookup_temperature(&self, ohm_100: i32) -> i32 {
-        if ohm_100 < self.ohm_lower_bound() {
-            self.interpolate_index(ohm_100, 0)
-        } else if ohm_100 > self.ohm_upper_bound() {
-            self.interpolate_index(ohm_100, self.data.len() - 2)
+        self.interpolate_index(ohm_100, self.interval_index(ohm_100))
+    }
+
+    /// Convert the specified temperature into a resistance, the inverse of
+    /// `lookup_temperature`.
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius_100` - The temperature in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The output resistance is in Ohms multiplied by 100.
+    ///
+    /// *Note*: This extrapolates from the bottom or top values if the
+    /// temperature is out of range.
+    pub fn lookup_resistance(&self, celsius_100: i32) -> i32 {
+        let index = self.temperature_interval_index(celsius_100);
+
+        let first = (self.lookup(index), self.reverse_index(index));
+        let second = (self.lookup(index + 1), self.reverse_index(index + 1));
+        interpolate(celsius_100, first, second)
+    }
+
+    /// The slope of the curve at the specified temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius_100` - The temperature in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The output is the change of resistance per degree Celsius in Ohms
+    /// multiplied by 100, rounded to the nearest value, e.g. `39` (0.39 Ohms
+    /// per degree) for a PT100 at 0 C°. It is the slope between the two table
+    /// entries surrounding the temperature, which is the slope used when
+    /// converting readings at that temperature.
+    pub fn local_slope(&self, celsius_100: i32) -> i32 {
+        let index = self.temperature_interval_index(celsius_100);
+        let d_ohm = self.lookup(index + 1) - self.lookup(index);
+        let step = self.step as i32;
+
+        div_round(d_ohm, step)
+    }
+
+    /// The index of the first of the two table entries surrounding the given
+    /// temperature.
+    fn temperature_interval_index(&self, celsius_100: i32) -> usize {
+        let offset = celsius_100 - self.min as i32 * 100;
+
+        offset
+            .div_euclid(self.step as i32 * 100)
+            .clamp(0, self.data.len() as i32 - 2) as usize
+    }
+
+    /// Convert the specified resistance value into a temperature with a
+    /// custom scale.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohm_100` - The resistance in Ohms multiplied by 100.
+    /// * `scale` - The factor the temperature in degrees Celsius is multiplied
+    ///             by, e.g. `1000` for millidegrees.
+    ///
+    /// # Remarks
+    ///
+    /// The interpolation is performed at the requested scale, so e.g. with a
+    /// scale of `1000` the last digit carries actual information rather than
+    /// being a multiple of 10 of the `lookup_temperature` result. With a scale
+    /// of `100` the result is identical to `lookup_temperature`.
+    pub fn lookup_temperature_scaled(&self, ohm_100: i32, scale: u32) -> i32 {
+        let index = self.interval_index(ohm_100);
+        let first = (self.reverse_index(index) as i64, self.lookup(index) as i64);
+        let second = (
+            self.reverse_index(index + 1) as i64,
+            self.lookup(index + 1) as i64,
+        );
+        let scale = scale as i64;
+
+        let numerator = (second.0 - first.0) * scale * (ohm_100 as i64 - first.1);
+        let denominator = (second.1 - first.1) * 100;
+
+        if denominator == 0 {
+            return (first.0 * scale / 100) as i32;
+        }
+
+        (numerator / denominator + first.0 * scale / 100) as i32
+    }
+
+    /// Convert a resistance in milliohms into a temperature in millidegrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohm_1000` - The resistance in Ohms multiplied by 1000.
+    ///
+    /// # Remarks
+    ///
+    /// The output temperature is in degrees Celsius multiplied by 1000. Unlike
+    /// `lookup_temperature_scaled` the resistance carries an extra digit, so
+    /// the last digit of the result reflects the resolution of the input
+    /// rather than only the interpolation. The arithmetic is performed in 64
+    /// bits.
+    pub fn lookup_temperature_milli(&self, ohm_1000: i32) -> i32 {
+        let index = self.interval_index(ohm_1000 / 10);
+        let first = (
+            self.reverse_index(index) as i64 * 10,
+            self.lookup(index) as i64 * 10,
+        );
+        let second = (
+            self.reverse_index(index + 1) as i64 * 10,
+            self.lookup(index + 1) as i64 * 10,
+        );
+
+        let numerator = (second.0 - first.0) * (ohm_1000 as i64 - first.1);
+        let denominator = second.1 - first.1;
+
+        if denominator == 0 {
+            return first.0 as i32;
+        }
+
+        (numerator / denominator + first.0) as i32
+    }
+
+    /// Convert the specified resistance value into a temperature, rejecting
+    /// values outside of the range covered by the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohm_100` - The resistance in Ohms multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// Behaves like `lookup_temperature`, but returns `None` rather than
+    /// extrapolating if the resistance is below the first or above the last
+    /// value of the table. For the built-in tables this is the case outside
+    /// of -200 C° to 860 C°, i.e. beyond the physical range of the sensor.
+    pub fn lookup_temperature_checked(&self, ohm_100: i32) -> Option<i32> {
+        if ohm_100 < self.lookup(0) || ohm_100 > self.lookup(self.data.len() - 1) {
+            None
         } else {
-            let index = match self.binary_search(ohm_100) {
-                Ok(val) => val,
-                Err(val) => val - 1,
-            };
-            self.interpolate_index(ohm_100 as i32, index)
+            Some(self.lookup_temperature(ohm_100))
+        }
+    }
+
+    /// Convert an uncertainty of the resistance into an uncertainty of the
+    /// temperature.
+    ///
+    /// # Arguments
+    ///
+    /// * `ohm_100` - The measured resistance in Ohms multiplied by 100.
+    /// * `ohm_uncertainty_100` - The uncertainty of the resistance in Ohms
+    ///                           multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The output is the uncertainty in degrees Celsius multiplied by 100,
+    /// rounded up. It is derived from the slope of the curve between the two
+    /// table entries surrounding the measured resistance, as the sensitivity
+    /// of the RTD decreases with temperature.
+    pub fn temperature_uncertainty(&self, ohm_100: i32, ohm_uncertainty_100: i32) -> i32 {
+        let index = self.interval_index(ohm_100);
+        let d_ohm = self.lookup(index + 1) - self.lookup(index);
+        let d_temp = self.step as i32 * 100;
+
+        if d_ohm <= 0 {
+            return d_temp;
+        }
+
+        (ohm_uncertainty_100 * d_temp + d_ohm - 1) / d_ohm
+    }
+}
+
+/// A lookup table wrapper that remembers the interval of the last lookup.
+///
+/// Consecutive readings usually change slowly and fall into the same or an
+/// adjacent interval of the table. Rather than performing a binary search on
+/// every lookup, this scans linearly from the last interval, which is faster
+/// for slowly changing readings. The results are identical to
+/// `LookupTable::lookup_temperature`.
+pub struct CachedLookup<'t, 'a, D> {
+    table: &'t LookupTable<'a, D>,
+    index: usize,
+}
+
+impl<'t, 'a, D> CachedLookup<'t, 'a, D>
+where
+    LookupTable<'a, D>: LookupToI32,
+{
+    /// Wrap the given lookup table.
+    pub fn new(table: &'t LookupTable<'a, D>) -> Self {
+        CachedLookup { table, index: 0 }
+    }
+
+    /// Convert the specified resistance value into a temperature, see
+    /// `LookupTable::lookup_temperature`.
+    pub fn lookup_temperature(&mut self, ohm_100: i32) -> i32 {
+        let last = self.table.data.len() - 2;
+
+        while self.index < last && self.table.lookup(self.index + 1) <= ohm_100 {
+            self.index += 1;
+        }
+        while self.index > 0 && self.table.lookup(self.index) > ohm_100 {
+            self.index -= 1;
+        }
+
+        self.table.interpolate_index(ohm_100, self.index)
+    }
+}
+
+/// Encode a value in hundredths as packed BCD.
+///
+/// # Arguments
+///
+/// * `value_100` - The value multiplied by 100, e.g. a temperature in degrees
+///                 Celsius multiplied by 100.
+///
+/// # Remarks
+///
+/// The nibbles of the result are, from the most significant:
+///
+/// - Bits 31-28: The sign, `0x0` for positive values and zero, `0xF` for
+///   negative values.
+/// - Bits 27-8: Five integer digits, most significant first.
+/// - Bits 7-0: Two fractional digits (tenths, then hundredths).
+///
+/// The digits encode the magnitude of the value, so e.g. -12.34 is encoded
+/// as `0xF000_1234` and 850.5 as `0x0008_5050`. Magnitudes above 99999.99
+/// saturate at `0x0999_9999`.
+pub fn hundredths_to_bcd(value_100: i32) -> u32 {
+    let sign = if value_100 < 0 { 0xF << 28 } else { 0 };
+    let mut magnitude = value_100.unsigned_abs().min(9_999_999);
+
+    let mut bcd = 0;
+    for nibble in 0..7 {
+        bcd |= (magnitude % 10) << (nibble * 4);
+        magnitude /= 10;
+    }
+
+    sign | bcd
+}
+
+/// How a value is rounded when reducing its precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards zero, e.g. 23.999 becomes 23.99 and -23.999 becomes
+    /// -23.99.
+    Truncate,
+    /// Round to the nearest value, ties towards positive infinity, e.g.
+    /// 23.995 becomes 24.00 and -23.995 becomes -23.99.
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, ties to the even value, e.g. 23.985
+    /// becomes 23.98, 23.995 becomes 24.00 and -23.995 becomes -24.00. This
+    /// avoids a bias when averaging rounded values.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Divide `value` by the positive `divisor`, rounding the result.
+    pub fn divide(&self, value: i32, divisor: i32) -> i32 {
+        let quotient = value.div_euclid(divisor);
+        let twice_remainder = value.rem_euclid(divisor) * 2;
+
+        match self {
+            RoundingMode::Truncate => value / divisor,
+            RoundingMode::HalfUp if twice_remainder >= divisor => quotient + 1,
+            RoundingMode::HalfEven if twice_remainder > divisor => quotient + 1,
+            RoundingMode::HalfEven if twice_remainder == divisor => quotient + (quotient & 1),
+            _ => quotient,
+        }
+    }
+}
+
+/// A unit of temperature that readings can be reported in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TempUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Rankine,
+}
+
+/// Divide and round to the nearest integer, with halves rounded away from zero.
+fn div_round(numerator: i32, denominator: i32) -> i32 {
+    if numerator >= 0 {
+        (numerator + denominator / 2) / denominator
+    } else {
+        (numerator - denominator / 2) / denominator
+    }
+}
+
+impl TempUnit {
+    /// Convert a temperature from degrees Celsius into this unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius_100` - The temperature in degrees Celsius multiplied by 100.
+    ///
+    /// # Remarks
+    ///
+    /// The output is the temperature in this unit multiplied by 100, e.g.
+    /// 21200 for 212.00 °F. The result is rounded to the nearest hundredth.
+    pub fn from_celsius(&self, celsius_100: i32) -> i32 {
+        match self {
+            TempUnit::Celsius => celsius_100,
+            TempUnit::Fahrenheit => div_round(celsius_100 * 9, 5) + 3_200,
+            TempUnit::Kelvin => celsius_100 + 27_315,
+            TempUnit::Rankine => div_round((celsius_100 + 27_315) * 9, 5),
+        }
+    }
+
+    /// The symbol of this unit, e.g. `°C`.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            TempUnit::Celsius => "°C",
+            TempUnit::Fahrenheit => "°F",
+            TempUnit::Kelvin => "K",
+            TempUnit::Rankine => "°R",
         }
     }
 }
 
+/// A temperature in degrees Celsius multiplied by 100, the scale of all
+/// temperatures read by the driver.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Celsius(pub i32);
+
+impl Celsius {
+    /// The temperature in degrees Celsius multiplied by 100.
+    pub fn hundredths(&self) -> i32 {
+        self.0
+    }
+
+    /// The whole degrees, truncated towards zero, e.g. `-5` for -5.07 °C.
+    pub fn whole(&self) -> i32 {
+        self.0 / 100
+    }
+
+    /// The hundredths of a degree of the magnitude, e.g. `7` for -5.07 °C.
+    pub fn fraction(&self) -> u8 {
+        (self.0.unsigned_abs() % 100) as u8
+    }
+
+    /// The temperature in degrees Fahrenheit multiplied by 100, see
+    /// `TempUnit::from_celsius`.
+    pub fn to_fahrenheit(&self) -> i32 {
+        TempUnit::Fahrenheit.from_celsius(self.0)
+    }
+}
+
+impl From<Celsius> for i32 {
+    fn from(celsius: Celsius) -> i32 {
+        celsius.0
+    }
+}
+
+impl core::fmt::Display for Celsius {
+    /// Formats the temperature with two decimals and the unit, e.g. `-5.07°C`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+
+        write!(
+            f,
+            "{}{}.{:02}{}",
+            sign,
+            self.whole().unsigned_abs(),
+            self.fraction(),
+            TempUnit::Celsius.suffix()
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl TempUnit {
+    /// Convert a temperature from degrees Celsius into this unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `celsius` - The temperature in degrees Celsius.
+    pub fn from_celsius_f32(&self, celsius: f32) -> f32 {
+        match self {
+            TempUnit::Celsius => celsius,
+            TempUnit::Fahrenheit => celsius * 1.8 + 32.0,
+            TempUnit::Kelvin => celsius + 273.15,
+            TempUnit::Rankine => (celsius + 273.15) * 1.8,
+        }
+    }
+}
+
+#[cfg(feature = "libm")]
+fn sqrt(val: f32) -> f32 {
+    libm::sqrtf(val)
+}
+
+#[cfg(all(feature = "std", not(feature = "libm")))]
+fn sqrt(val: f32) -> f32 {
+    val.sqrt()
+}
+
+/// Callendar–Van Dusen coefficients as specified by IEC 60751.
+#[cfg(any(feature = "std", feature = "libm"))]
+const CVD_A: f32 = 3.9083e-3;
+#[cfg(any(feature = "std", feature = "libm"))]
+const CVD_B: f32 = -5.775e-7;
+#[cfg(any(feature = "std", feature = "libm"))]
+const CVD_C: f32 = -4.18301e-12;
+
+/// Convert a resistance into a temperature by solving the Callendar–Van Dusen
+/// equation directly rather than using a lookup table.
+///
+/// # Arguments
+///
+/// * `ohms` - The resistance of the RTD in Ohms.
+/// * `r0` - The nominal resistance of the RTD at 0 C°, e.g. `100.0` for a
+///          PT100.
+///
+/// # Remarks
+///
+/// The output is the temperature in degrees Celsius. Above 0 C° the quadratic
+/// equation is solved exactly, below it the result is refined using a few
+/// Newton iterations of the full equation.
+///
+/// *Note*: This requires either the `std` or the `libm` feature, the latter
+/// for `no_std` targets.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn cvd_temperature(ohms: f32, r0: f32) -> f32 {
+    let ratio = ohms / r0;
+    // numerically stable form of the quadratic formula
+    let mut t = 2.0 * (ratio - 1.0) / (CVD_A + sqrt(CVD_A * CVD_A - 4.0 * CVD_B * (1.0 - ratio)));
+
+    if t < 0.0 {
+        for _ in 0..4 {
+            let t2 = t * t;
+            let t3 = t2 * t;
+            let f = 1.0 + CVD_A * t + CVD_B * t2 + CVD_C * (t - 100.0) * t3 - ratio;
+            let df = CVD_A + 2.0 * CVD_B * t + CVD_C * (4.0 * t3 - 300.0 * t2);
+            t -= f / df;
+        }
+    }
+
+    t
+}
+
 /// This lookup table contains the resistance values for a PT100 RTD ranging
 /// from 0 C° up to 130 C° in steps of 10 C°, corresponding to a range from
 /// 100.0 Ohms to 149.83 Ohms.
@@ -110,8 +586,10 @@ pub const LOOKUP_TABLE_PT100_SHORT: LookupTable<'static, u16> = LookupTable {
 };
 
 /// This lookup table contains the resistance values for a PT100 RTD ranging
-/// from -200 C° up to 800 C°, corresponding to a range from 18.52 Ohms to
-/// 369.71 Ohms. Calculated using `fn make_lookup()` below.
+/// from -200 C° up to 860 C° in steps of 20 C°, corresponding to a range from
+/// 18.52 Ohms to 393.40 Ohms. This covers the full -200 C° to 850 C° range
+/// specified by IEC 60751. Calculated using `fn make_lookup()` below. 0 C° is
+/// an exact table point (index 10), so the ice point converts exactly.
 pub const LOOKUP_VEC_PT100: LookupTable<'static, u32> = LookupTable {
     min: -200,
     step: 20,
@@ -119,13 +597,15 @@ pub const LOOKUP_VEC_PT100: LookupTable<'static, u32> = LookupTable {
         1852, 2710, 3554, 4388, 5211, 6026, 6833, 7633, 8427, 9216, 10000, 10779, 11554, 12324,
         13090, 13851, 14607, 15358, 16105, 16848, 17586, 18319, 19047, 19771, 20490, 21205, 21915,
         22621, 23321, 24018, 24709, 25396, 26078, 26756, 27429, 28098, 28762, 29421, 30075, 30725,
-        31371, 32012, 32648, 33279, 33906, 34528, 35146, 35759, 36367, 36971,
+        31371, 32012, 32648, 33279, 33906, 34528, 35146, 35759, 36367, 36971, 37570, 38165, 38755,
+        39340,
     ],
 };
 
 /// This lookup table contains the resistance values for a PT1000 RTD ranging
-/// from -200 C° up to 800 C°, corresponding to a range from 185.20 Ohms to
-/// 3697.12 Ohms. Calculated using `fn make_lookup()` below.
+/// from -200 C° up to 860 C° in steps of 20 C°, corresponding to a range from
+/// 185.20 Ohms to 3934.02 Ohms. This covers the full -200 C° to 850 C° range
+/// specified by IEC 60751. Calculated using `fn make_lookup()` below.
 pub const LOOKUP_VEC_PT1000: LookupTable<'static, u32> = LookupTable {
     min: -200,
     step: 20,
@@ -134,16 +614,29 @@ pub const LOOKUP_VEC_PT1000: LookupTable<'static, u32> = LookupTable {
         115541, 123242, 130897, 138505, 146068, 153584, 161054, 168478, 175856, 183188, 190473,
         197712, 204905, 212052, 219152, 226206, 233214, 240176, 247092, 253961, 260785, 267562,
         274293, 280978, 287616, 294208, 300754, 307254, 313708, 320116, 326477, 332792, 339061,
-        345284, 351460, 357590, 363674, 369712,
+        345284, 351460, 357590, 363674, 369712, 375704, 381650, 387549, 393402,
     ],
 };
 
+// The high resolution tables generated by `build.rs`, see the crate
+// documentation.
+#[cfg(feature = "generated-table")]
+include!(concat!(env!("OUT_DIR"), "/lookup_table.rs"));
+
 #[cfg(test)]
 mod test {
     use super::{
-        index, lookup_temperature, lookup_temperature_pt1000, reverse_index, MAX, MIN, STEP,
+        interpolate, CachedLookup, LookupTable, TempUnit, LOOKUP_VEC_PT100, LOOKUP_VEC_PT1000,
     };
 
+    const MIN: i16 = -200;
+    const MAX: i16 = 880;
+    const STEP: usize = 20;
+
+    fn index(t: i16) -> usize {
+        ((t - MIN) as usize) / STEP
+    }
+
     const A: f64 = 3.9083e-3;
     const B: f64 = -5.775e-7;
     const C: f64 = -4.18301e-12;
@@ -175,7 +668,7 @@ mod test {
         */
 
         // according to wikipedia there are more accurate formula
-        let mut arr = [0u32; 50];
+        let mut arr = [0u32; 54];
 
         for t in (MIN..MAX).step_by(STEP) {
             let c = if t < 0 { C } else { 0.0 };
@@ -189,12 +682,15 @@ mod test {
         }
 
         if r0 == 100 {
+            assert_eq!(&arr[..], LOOKUP_VEC_PT100.data);
+
             // value taken from https://datasheets.maximintegrated.com/en/ds/MAX31865.pdf TABLE 9
             assert_eq!(arr[index(-200i16)], 1_852);
             assert_eq!(arr[index(-100i16)], 6_026);
             assert_eq!(arr[index(0i16)], 10_000);
             assert_eq!(arr[index(100i16)], 13_851);
         } else if r0 == 1000 {
+            assert_eq!(&arr[..], LOOKUP_VEC_PT1000.data);
             assert_eq!(arr[index(0i16)], 100_000);
         }
 
@@ -211,22 +707,277 @@ mod test {
 
     #[test]
     fn test_reverse_index() {
-        assert_eq!(reverse_index(0), -20_000); // -200 C°
-        assert_eq!(reverse_index(1), -18_000); // -180 C°
-        assert_eq!(reverse_index(10), 0);
-        assert_eq!(reverse_index(20), 20_000); // 20 C°
+        assert_eq!(LOOKUP_VEC_PT100.reverse_index(0), -20_000); // -200 C°
+        assert_eq!(LOOKUP_VEC_PT100.reverse_index(1), -18_000); // -180 C°
+        assert_eq!(LOOKUP_VEC_PT100.reverse_index(10), 0);
+        assert_eq!(LOOKUP_VEC_PT100.reverse_index(20), 20_000); // 200 C°
+    }
+
+    #[test]
+    fn test_local_slope() {
+        assert_eq!(LOOKUP_VEC_PT100.local_slope(0), 39);
+        assert_eq!(LOOKUP_VEC_PT100.local_slope(-20_000), 43);
+        assert_eq!(LOOKUP_VEC_PT100.local_slope(84_000), 29);
+        assert_eq!(LOOKUP_VEC_PT1000.local_slope(0), 390);
+    }
+
+    #[test]
+    fn test_temperature_range() {
+        assert_eq!(LOOKUP_VEC_PT100.temperature_range(), (-20_000, 86_000));
+        assert_eq!(LOOKUP_VEC_PT1000.temperature_range(), (-20_000, 86_000));
     }
 
     #[test]
     fn test_lookup() {
-        assert!(lookup_temperature(0).is_none());
+        assert!(LOOKUP_VEC_PT100.lookup_temperature_checked(0).is_none());
 
-        assert_eq!(lookup_temperature(10_000).unwrap(), 0);
-        assert_eq!(lookup_temperature(10_390).unwrap(), 1_001);
-        assert_eq!(lookup_temperature(20_000).unwrap(), 26_636);
-        assert_eq!(lookup_temperature(2_000).unwrap(), -19_656);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(10_000), 0);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(10_390), 1_001);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(20_000), 26_636);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(2_000), -19_656);
 
-        assert_eq!(lookup_temperature_pt1000(100_000).unwrap(), 0);
-        assert_eq!(lookup_temperature_pt1000(103_900).unwrap(), 1_000);
+        assert_eq!(LOOKUP_VEC_PT1000.lookup_temperature(100_000), 0);
+        assert_eq!(LOOKUP_VEC_PT1000.lookup_temperature(103_900), 1_000);
+    }
+
+    #[test]
+    fn test_resistance_range() {
+        let (low, high) = LOOKUP_VEC_PT100.resistance_range();
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(low), -20_000);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(high), 86_000);
+    }
+
+    #[test]
+    fn test_zero_anchor() {
+        for table in [LOOKUP_VEC_PT100, LOOKUP_VEC_PT1000] {
+            let r0 = table.data[10] as i32;
+            assert_eq!(table.reverse_index(10), 0);
+            assert_eq!(table.lookup_temperature(r0), 0);
+            assert_eq!(table.lookup_temperature_milli(r0 * 10), 0);
+            assert_eq!(table.lookup_resistance(0), r0);
+
+            // The anchor starts the interval above it rather than ending the
+            // one below, and the neighbours fall on the correct side of it.
+            assert_eq!(table.interval_index(r0), 10);
+            assert_eq!(table.interval_index(r0 - 1), 9);
+            let delta = r0 / 1_000;
+            assert!(table.lookup_temperature(r0 - delta) < 0);
+            assert!(table.lookup_temperature(r0 + delta) > 0);
+        }
+    }
+
+    #[cfg(feature = "generated-table")]
+    #[test]
+    fn test_generated_table() {
+        use super::{LOOKUP_VEC_PT1000_GENERATED, LOOKUP_VEC_PT100_GENERATED};
+
+        for (celsius_100, ohm_100) in [
+            (-20_000, 1_852),
+            (0, 10_000),
+            (10_000, 13_851),
+            (85_000, 39_048),
+        ] {
+            assert_eq!(
+                LOOKUP_VEC_PT100_GENERATED.lookup_resistance(celsius_100),
+                ohm_100
+            );
+            assert_eq!(
+                LOOKUP_VEC_PT100_GENERATED.lookup_temperature(ohm_100),
+                celsius_100
+            );
+        }
+        assert_eq!(
+            LOOKUP_VEC_PT1000_GENERATED.lookup_temperature(138_506),
+            10_000
+        );
+    }
+
+    #[test]
+    fn test_lookup_milli() {
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature_milli(100_000), 0);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature_milli(100_010), 25);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature_milli(138_506), 99_989);
+
+        for ohm_100 in (2_000..38_000).step_by(313) {
+            assert_eq!(
+                LOOKUP_VEC_PT100.lookup_temperature_milli(ohm_100 * 10),
+                LOOKUP_VEC_PT100.lookup_temperature_scaled(ohm_100, 1000)
+            );
+        }
+    }
+
+    #[test]
+    fn test_lookup_resistance() {
+        assert_eq!(LOOKUP_VEC_PT100.lookup_resistance(0), 10_000);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_resistance(-19_000), 2_281);
+        assert_eq!(LOOKUP_VEC_PT1000.lookup_resistance(1_000), 103_897);
+
+        for celsius_100 in (-20_000..86_000).step_by(1_234) {
+            let ohm_100 = LOOKUP_VEC_PT100.lookup_resistance(celsius_100);
+            let back = LOOKUP_VEC_PT100.lookup_temperature(ohm_100);
+            assert!((back - celsius_100).abs() <= 5, "{}", celsius_100);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_cvd_temperature() {
+        use super::cvd_temperature;
+
+        assert!(cvd_temperature(100.0, 100.0).abs() < 0.02);
+        assert!((cvd_temperature(138.51, 100.0) - 100.0).abs() < 0.02);
+        assert!((cvd_temperature(60.26, 100.0) + 100.0).abs() < 0.02);
+        assert!((cvd_temperature(185.2, 1000.0) + 200.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // exact points
+        assert_eq!(interpolate(10_000, (0, 10_000), (2_000, 10_779)), 0);
+        assert_eq!(interpolate(10_779, (0, 10_000), (2_000, 10_779)), 2_000);
+        // midpoint
+        assert_eq!(interpolate(15_000, (0, 10_000), (1_000, 20_000)), 500);
+        // below the first point
+        assert_eq!(interpolate(5_000, (0, 10_000), (1_000, 20_000)), -500);
+        // above the second point
+        assert_eq!(interpolate(30_000, (0, 10_000), (1_000, 20_000)), 2_000);
+        // equal resistances
+        assert_eq!(interpolate(10_000, (0, 10_000), (1_000, 10_000)), 0);
+    }
+
+    #[test]
+    fn test_lookup_table_points() {
+        for (i, ohm_100) in LOOKUP_VEC_PT100.data.iter().enumerate() {
+            assert_eq!(
+                LOOKUP_VEC_PT100.lookup_temperature(*ohm_100 as i32),
+                (i as i32 * 20 - 200) * 100
+            );
+        }
+
+        // extrapolation below the first and above the last entry
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(1_000), -21_986);
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(40_000), 88_256);
+    }
+
+    #[test]
+    fn test_lookup_duplicate_values() {
+        let table: LookupTable<'static, u16> = LookupTable {
+            min: 0,
+            step: 10,
+            data: &[100, 200, 200, 300],
+        };
+
+        // either of the duplicate points may be found, but it must not panic
+        let temp = table.lookup_temperature(200);
+        assert!((1_000..=2_000).contains(&temp));
+        let temp = table.lookup_temperature_scaled(200, 1000);
+        assert!((10_000..=20_000).contains(&temp));
+        assert_eq!(table.lookup_temperature(250), 2_500);
+    }
+
+    #[test]
+    fn test_lookup_scaled() {
+        for ohm_100 in [1_852, 2_000, 10_000, 10_390, 20_000, 39_340, 40_000].iter() {
+            assert_eq!(
+                LOOKUP_VEC_PT100.lookup_temperature_scaled(*ohm_100, 100),
+                LOOKUP_VEC_PT100.lookup_temperature(*ohm_100)
+            );
+        }
+
+        // 10.0128 C°, which is truncated to 10.01 C° at the default scale
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature(10_390), 1_001);
+        assert_eq!(
+            LOOKUP_VEC_PT100.lookup_temperature_scaled(10_390, 1000),
+            10_012
+        );
+        assert_eq!(
+            LOOKUP_VEC_PT100.lookup_temperature_scaled(2_000, 1000),
+            -196_551
+        );
+    }
+
+    #[test]
+    fn test_cached_lookup() {
+        let mut cached = CachedLookup::new(&LOOKUP_VEC_PT100);
+
+        for ohm_100 in (0..42_000).step_by(7).chain((0..42_000).rev().step_by(13)) {
+            assert_eq!(
+                cached.lookup_temperature(ohm_100),
+                LOOKUP_VEC_PT100.lookup_temperature(ohm_100)
+            );
+        }
+        assert_eq!(cached.lookup_temperature(39_340), 86_000);
+        assert_eq!(cached.lookup_temperature(1_000), -21_986);
+    }
+
+    #[test]
+    fn test_lookup_checked() {
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature_checked(1_851), None);
+        assert_eq!(
+            LOOKUP_VEC_PT100.lookup_temperature_checked(1_852),
+            Some(-20_000)
+        );
+        assert_eq!(
+            LOOKUP_VEC_PT100.lookup_temperature_checked(39_340),
+            Some(86_000)
+        );
+        assert_eq!(LOOKUP_VEC_PT100.lookup_temperature_checked(39_341), None);
+    }
+
+    #[test]
+    fn test_celsius() {
+        use super::Celsius;
+
+        let temp = Celsius(-507);
+        assert_eq!(temp.whole(), -5);
+        assert_eq!(temp.fraction(), 7);
+        assert_eq!(format!("{}", temp), "-5.07°C");
+        assert_eq!(format!("{}", Celsius(-50)), "-0.50°C");
+        assert_eq!(Celsius(10_000).to_fahrenheit(), 21_200);
+        assert_eq!(i32::from(Celsius(2_512)), 2_512);
+    }
+
+    #[test]
+    fn test_bcd() {
+        use super::hundredths_to_bcd;
+
+        assert_eq!(hundredths_to_bcd(0), 0);
+        assert_eq!(hundredths_to_bcd(-1_234), 0xF000_1234);
+        assert_eq!(hundredths_to_bcd(85_050), 0x0008_5050);
+        assert_eq!(hundredths_to_bcd(-5), 0xF000_0005);
+        assert_eq!(hundredths_to_bcd(i32::MIN), 0xF999_9999);
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        use super::RoundingMode;
+
+        let cases = [
+            // value, truncate, half up, half even
+            (23_999, 2_399, 2_400, 2_400),
+            (23_994, 2_399, 2_399, 2_399),
+            (23_995, 2_399, 2_400, 2_400),
+            (23_985, 2_398, 2_399, 2_398),
+            (-23_999, -2_399, -2_400, -2_400),
+            (-23_995, -2_399, -2_399, -2_400),
+            (-23_985, -2_398, -2_398, -2_398),
+        ];
+
+        for (value, truncate, half_up, half_even) in cases {
+            assert_eq!(RoundingMode::Truncate.divide(value, 10), truncate);
+            assert_eq!(RoundingMode::HalfUp.divide(value, 10), half_up);
+            assert_eq!(RoundingMode::HalfEven.divide(value, 10), half_even);
+        }
+    }
+
+    #[test]
+    fn test_temp_units() {
+        assert_eq!(TempUnit::Celsius.from_celsius(10_000), 10_000);
+        assert_eq!(TempUnit::Fahrenheit.from_celsius(10_000), 21_200);
+        assert_eq!(TempUnit::Fahrenheit.from_celsius(-4_000), -4_000);
+        assert_eq!(TempUnit::Fahrenheit.from_celsius(1), 3_202);
+        assert_eq!(TempUnit::Fahrenheit.from_celsius(-1), 3_198);
+        assert_eq!(TempUnit::Kelvin.from_celsius(-27_315), 0);
+        assert_eq!(TempUnit::Rankine.from_celsius(0), 49_167);
     }
 }
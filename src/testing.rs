@@ -0,0 +1,279 @@
+//! A simulated MAX31865 for testing applications without hardware
+//!
+//! The `SimulatedDevice` holds the register contents of a MAX31865 and hands
+//! out SPI and pin handles which can be passed to `Max31865::new`, so that
+//! application logic can be tested end to end against the real driver code.
+//!
+//! ```
+//! use max31865::testing::SimulatedDevice;
+//! use max31865::{FilterMode, Max31865, Rtd, SensorType};
+//!
+//! let device = SimulatedDevice::new(Rtd::Pt100, 40000);
+//! device.set_temperature(2500);
+//!
+//! let mut max = Max31865::new(device.spi(), device.ncs(), device.rdy()).unwrap();
+//! max.configure(true, true, false, SensorType::TwoOrFourWire, FilterMode::Filter50Hz)
+//!     .unwrap();
+//! let temp = max.read_default_conversion().unwrap();
+//! assert!((temp - 2500).abs() <= 5);
+//! ```
+
+use core::cell::RefCell;
+use core::convert::Infallible;
+
+use crate::hal::blocking::spi;
+use crate::hal::digital::v2::{InputPin, OutputPin};
+use crate::{
+    Rtd, RtdCurve, CONFIG_CONVERSION_MODE, CONFIG_FAULT_CLEAR, CONFIG_ONE_SHOT,
+    FAULT_HIGH_THRESHOLD, FAULT_LOW_THRESHOLD, REGISTER_COUNT, W,
+};
+
+const CONFIG: usize = 0x00;
+const RTD_MSB: usize = 0x01;
+const RTD_LSB: usize = 0x02;
+const HIGH_THRESHOLD_MSB: usize = 0x03;
+const LOW_THRESHOLD_MSB: usize = 0x05;
+const FAULT_STATUS: usize = 0x07;
+
+struct State {
+    registers: [u8; REGISTER_COUNT],
+    code: u16,
+    faults: u8,
+    pending: bool,
+}
+
+/// A simulated MAX31865 with a settable RTD resistance.
+///
+/// # Remarks
+///
+/// Reads of the RTD registers return the code corresponding to the set
+/// resistance. Threshold faults are raised according to the threshold
+/// registers, other faults can be injected with `set_faults`. The ready pin
+/// is always asserted in automatic conversion mode, and in normally off mode
+/// after a one-shot conversion was triggered until the RTD registers are read.
+pub struct SimulatedDevice {
+    rtd: Rtd,
+    reference_resistor: u32,
+    state: RefCell<State>,
+}
+
+impl SimulatedDevice {
+    /// Create a new simulated device in its power on state, measuring the
+    /// nominal resistance of the RTD (0 C°).
+    ///
+    /// # Arguments
+    ///
+    /// * `rtd` - The RTD type used by `set_temperature`.
+    /// * `reference_resistor` - The simulated reference resistance in ohms
+    ///                          multiplied by 100.
+    pub fn new(rtd: Rtd, reference_resistor: u32) -> Self {
+        let mut registers = [0; REGISTER_COUNT];
+        registers[HIGH_THRESHOLD_MSB] = 0xFF;
+        registers[HIGH_THRESHOLD_MSB + 1] = 0xFF;
+
+        let device = SimulatedDevice {
+            rtd,
+            reference_resistor,
+            state: RefCell::new(State {
+                registers,
+                code: 0,
+                faults: 0,
+                pending: false,
+            }),
+        };
+        device.set_resistance(rtd.nominal_resistance());
+
+        device
+    }
+
+    /// Set the simulated RTD resistance in ohms multiplied by 100.
+    pub fn set_resistance(&self, ohms_100: u32) {
+        let code = crate::conversion::ohms100_to_code(ohms_100, self.reference_resistor);
+        self.state.borrow_mut().code = code;
+        self.update();
+    }
+
+    /// Set the simulated temperature in degrees Celsius multiplied by 100.
+    pub fn set_temperature(&self, celsius_100: i32) {
+        let ohms = self.rtd.lookup_table().lookup_resistance(celsius_100);
+        self.set_resistance(ohms.max(0) as u32);
+    }
+
+    /// Inject fault status bits, e.g. `0x04` for an over or under voltage
+    /// fault. They are latched until cleared through the configuration
+    /// register.
+    pub fn set_faults(&self, faults: u8) {
+        self.state.borrow_mut().faults |= faults;
+        self.update();
+    }
+
+    /// The SPI bus of the device.
+    pub fn spi(&self) -> SimulatedSpi<'_> {
+        SimulatedSpi { device: self }
+    }
+
+    /// The chip select pin of the device.
+    pub fn ncs(&self) -> SimulatedNcs {
+        SimulatedNcs
+    }
+
+    /// The ready pin of the device.
+    pub fn rdy(&self) -> SimulatedRdy<'_> {
+        SimulatedRdy { device: self }
+    }
+
+    fn update(&self) {
+        let mut state = self.state.borrow_mut();
+        let code = state.code;
+        let r = &state.registers;
+        let high = u16::from_be_bytes([r[HIGH_THRESHOLD_MSB], r[HIGH_THRESHOLD_MSB + 1]]) >> 1;
+        let low = u16::from_be_bytes([r[LOW_THRESHOLD_MSB], r[LOW_THRESHOLD_MSB + 1]]) >> 1;
+
+        if code >= high {
+            state.faults |= FAULT_HIGH_THRESHOLD;
+        }
+        if code < low {
+            state.faults |= FAULT_LOW_THRESHOLD;
+        }
+
+        let raw = (code << 1) | (state.faults != 0) as u16;
+        state.registers[RTD_MSB..=RTD_LSB].copy_from_slice(&raw.to_be_bytes());
+        state.registers[FAULT_STATUS] = state.faults;
+    }
+
+    fn transfer(&self, words: &mut [u8]) {
+        let address = match words.first() {
+            Some(address) => *address,
+            None => return,
+        };
+
+        if address & W != 0 {
+            let start = (address & !W) as usize;
+            for (offset, word) in words[1..].iter().enumerate() {
+                self.write_register(start + offset, *word);
+            }
+            self.update();
+            return;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let start = address as usize;
+        words[0] = 0;
+        for (offset, word) in words[1..].iter_mut().enumerate() {
+            let reg = start + offset;
+            *word = state.registers.get(reg).copied().unwrap_or(0);
+            if reg == RTD_LSB {
+                state.pending = false;
+            }
+        }
+    }
+
+    fn write_register(&self, reg: usize, value: u8) {
+        let mut state = self.state.borrow_mut();
+
+        match reg {
+            CONFIG => {
+                if value & CONFIG_FAULT_CLEAR != 0 {
+                    state.faults = 0;
+                }
+                if value & CONFIG_ONE_SHOT != 0 {
+                    state.pending = true;
+                }
+                state.registers[CONFIG] = value & !(CONFIG_FAULT_CLEAR | CONFIG_ONE_SHOT);
+            }
+            HIGH_THRESHOLD_MSB..=0x06 => state.registers[reg] = value,
+            _ => {}
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        let state = self.state.borrow();
+        state.pending || state.registers[CONFIG] & CONFIG_CONVERSION_MODE != 0
+    }
+}
+
+/// The SPI bus of a `SimulatedDevice`.
+pub struct SimulatedSpi<'a> {
+    device: &'a SimulatedDevice,
+}
+
+impl spi::Transfer<u8> for SimulatedSpi<'_> {
+    type Error = Infallible;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Infallible> {
+        self.device.transfer(words);
+        Ok(words)
+    }
+}
+
+impl spi::Write<u8> for SimulatedSpi<'_> {
+    type Error = Infallible;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Infallible> {
+        let mut buffer = [0u8; REGISTER_COUNT + 1];
+        let len = words.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&words[..len]);
+        self.device.transfer(&mut buffer[..len]);
+        Ok(())
+    }
+}
+
+/// The chip select pin of a `SimulatedDevice`. Driving it has no effect.
+pub struct SimulatedNcs;
+
+impl OutputPin for SimulatedNcs {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// The ready pin of a `SimulatedDevice`, low when a conversion is available.
+pub struct SimulatedRdy<'a> {
+    device: &'a SimulatedDevice,
+}
+
+impl InputPin for SimulatedRdy<'_> {
+    type Error = Infallible;
+
+    fn is_high(&self) -> Result<bool, Infallible> {
+        Ok(!self.device.is_ready())
+    }
+
+    fn is_low(&self) -> Result<bool, Infallible> {
+        Ok(self.device.is_ready())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SimulatedDevice;
+    use crate::{FilterMode, Max31865, Rtd, SensorType};
+
+    #[test]
+    fn threshold_fault() {
+        let device = SimulatedDevice::new(Rtd::Pt100, 40000);
+        let mut max = Max31865::new(device.spi(), device.ncs(), device.rdy()).unwrap();
+        max.configure(
+            true,
+            true,
+            false,
+            SensorType::TwoOrFourWire,
+            FilterMode::Filter50Hz,
+        )
+        .unwrap();
+
+        device.set_temperature(-4000);
+        let temp = max.read_default_conversion().unwrap();
+        assert!((temp + 4000).abs() <= 5, "{}", temp);
+        assert!(!max.read_fault_status().unwrap().is_fault());
+
+        device.set_resistance(40000);
+        assert!(max.read_fault_status().unwrap().high_threshold());
+    }
+}